@@ -0,0 +1,58 @@
+//! Generates the registry of solutions that the `in_process` feature compiles into the `advent_of_code` binary.
+//! Every `src/bin/<year>/<day>.rs` that uses `solution!` becomes a module of `$OUT_DIR/solutions.rs`.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+    let solutions = find_solutions(&bin_dir);
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, day, path) in &solutions {
+        let module = format!("y{year}_{day}");
+        modules.push_str(&format!(
+            "// solutions are linted as their own binaries.\n#[allow(warnings, clippy::all, clippy::pedantic)]\n#[path = {path:?}]\nmod {module};\n"
+        ));
+        entries.push_str(&format!(
+            "    advent_of_code::template::solution::RegisteredSolution {{ id: {module}::DAY, run: |input| advent_of_code::template::runner::run_solution(&{module}::solution(), input) }},\n"
+        ));
+    }
+
+    let registry = format!(
+        "{modules}\npub static REGISTRY: advent_of_code::template::solution::Registry = advent_of_code::template::solution::Registry(&[\n{entries}]);\n"
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).unwrap();
+}
+
+/// Every solution in `src/bin`, as year, zero-padded day and path, in ascending order.
+fn find_solutions(bin_dir: &Path) -> Vec<(u16, String, PathBuf)> {
+    let mut solutions = vec![];
+
+    for year_dir in fs::read_dir(bin_dir).into_iter().flatten().filter_map(Result::ok) {
+        let Some(year) = year_dir.file_name().to_str().and_then(|name| name.parse::<u16>().ok()) else {
+            continue;
+        };
+
+        for file in fs::read_dir(year_dir.path()).into_iter().flatten().filter_map(Result::ok) {
+            let path = file.path();
+            let Some(day) = path.file_stem().and_then(|stem| stem.to_str()).filter(|stem| stem.len() == 2 && stem.parse::<u8>().is_ok()) else {
+                continue;
+            };
+
+            let uses_macro = path.extension().is_some_and(|ext| ext == "rs") && fs::read_to_string(&path).is_ok_and(|source| source.contains("solution!("));
+            if uses_macro {
+                solutions.push((year, day.to_string(), path.clone()));
+            }
+        }
+    }
+
+    solutions.sort();
+    solutions
+}
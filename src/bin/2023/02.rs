@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 #[derive(Debug, Clone)]
 struct Game {
@@ -3,21 +3,22 @@ use std::{
     vec,
 };
 
+use advent_of_code::grid::{Coordinate, Grid};
 use anyhow::Result;
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 #[derive(Debug, Clone)]
-struct Grid {
-    cells: Vec<Vec<Cell>>,
+struct Schematic {
+    cells: Grid<Cell>,
 
     part_numbers: Vec<PartNumber>,
-    part_number_map: HashMap<(usize, usize), PartNumber>,
+    part_number_map: HashMap<Coordinate, PartNumber>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 struct PartNumber {
     num: u32,
-    indices: Vec<(usize, usize)>,
+    indices: Vec<Coordinate>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -27,15 +28,15 @@ enum Cell {
     Dot,
 }
 
-impl Grid {
-    fn from_cells(cells: Vec<Vec<Cell>>) -> Self {
-        let mut grid = Grid {
+impl Schematic {
+    fn from_cells(cells: Grid<Cell>) -> Self {
+        let mut grid = Schematic {
             cells: cells.clone(),
             part_numbers: vec![],
             part_number_map: HashMap::new(),
         };
 
-        for (row, r) in cells.iter().enumerate() {
+        for (row, r) in cells.rows().enumerate() {
             let mut part_number: Option<PartNumber> = None;
 
             for (column, cell) in r.iter().enumerate() {
@@ -45,12 +46,12 @@ impl Grid {
                             Some(ref mut p) => {
                                 p.num *= 10;
                                 p.num += num;
-                                p.indices.push((row, column));
+                                p.indices.push(Coordinate::new(row, column));
                             }
                             None => {
                                 part_number = Some(PartNumber {
                                     num: *num,
-                                    indices: vec![(row, column)],
+                                    indices: vec![Coordinate::new(row, column)],
                                 });
                             }
                         };
@@ -64,13 +65,10 @@ impl Grid {
                         continue;
                     }
 
-                    let symbol_adjacent = p.indices.iter().any(|(cell_row, cell_column)| {
-                        adjacent_cells(&cells, *cell_row, *cell_column)
-                            .iter()
-                            .any(|(_, c)| match c {
-                                Cell::Symbol => true,
-                                _ => false,
-                            })
+                    let symbol_adjacent = p.indices.iter().any(|&index| {
+                        cells
+                            .neighbors_diagonal(index)
+                            .any(|c| matches!(cells[c], Cell::Symbol))
                     });
 
                     if symbol_adjacent {
@@ -90,74 +88,38 @@ impl Grid {
     fn gear_ratios_sum(&self) -> u32 {
         let mut sum = 0;
 
-        for (row, r) in self.cells.iter().enumerate() {
-            for (column, cell) in r.iter().enumerate() {
-                match cell {
-                    Cell::Symbol => {
-                        // does it have exactly 2 adjacent part numbers
-                        let part_numbers: Vec<_> = adjacent_cells(&self.cells, row, column)
-                            .iter()
-                            .filter_map(|((row, column), _)| {
-                                self.part_number_map.get(&(*row, *column))
-                            })
-                            .collect::<HashSet<_>>()
-                            .into_iter()
-                            .collect();
-
-                        if part_numbers.len() != 2 {
-                            continue;
-                        }
-                        sum += part_numbers[0].num * part_numbers[1].num
+        for (coordinate, cell) in self.cells.iter() {
+            match cell {
+                Cell::Symbol => {
+                    // does it have exactly 2 adjacent part numbers
+                    let part_numbers: Vec<_> = self
+                        .cells
+                        .neighbors_diagonal(coordinate)
+                        .filter_map(|c| self.part_number_map.get(&c))
+                        .collect::<HashSet<_>>()
+                        .into_iter()
+                        .collect();
+
+                    if part_numbers.len() != 2 {
+                        continue;
                     }
-                    _ => {}
+                    sum += part_numbers[0].num * part_numbers[1].num
                 }
+                _ => {}
             }
         }
         sum
     }
 }
 
-fn adjacent_cells(cells: &Vec<Vec<Cell>>, row: usize, col: usize) -> HashMap<(usize, usize), Cell> {
-    let mut adjacents = HashMap::new();
-    let mut offsets: Vec<(usize, usize)> = vec![(row, col + 1), (row + 1, col), (row + 1, col + 1)];
-
-    if row > 0 {
-        offsets.push((row - 1, col));
-        offsets.push((row - 1, col + 1));
-        if col > 0 {
-            offsets.push((row - 1, col - 1));
-        }
-    }
-    if col > 0 {
-        offsets.push((row, col - 1));
-        offsets.push((row + 1, col - 1));
-    }
-
-    for offset in offsets {
-        if let Some(r) = cells.get(offset.0) {
-            if let Some(cell) = r.get(offset.1) {
-                adjacents.insert((offset.0, offset.1), *cell);
-            }
-        }
-    }
-    adjacents
-}
+fn parse_grid(input: &str) -> Result<Schematic> {
+    let cells = Grid::parse(input, |c| match c {
+        '0'..='9' => Cell::Num(c.to_digit(10).expect("parse digit")),
+        '.' => Cell::Dot,
+        _ => Cell::Symbol,
+    })?;
 
-fn parse_grid(input: &str) -> Result<Grid> {
-    let cells: Vec<_> = input
-        .split('\n')
-        .map(|row| {
-            row.chars()
-                .map(|c| match c {
-                    '0'..='9' => Cell::Num(c.to_digit(10).expect("parse digit")),
-                    '.' => Cell::Dot,
-                    _ => Cell::Symbol,
-                })
-                .collect()
-        })
-        .collect();
-
-    Ok(Grid::from_cells(cells))
+    Ok(Schematic::from_cells(cells))
 }
 
 pub fn part_one(input: &str) -> Result<u32> {
@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use anyhow::{Result};
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 #[derive(Debug)]
 struct Card {
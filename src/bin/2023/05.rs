@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use advent_of_code::range_set::{Interval, RangeSet};
+use anyhow::{anyhow, bail, Context, Result};
+advent_of_code::solution!(2023, 5, parse = parse);
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+
+    // keyed by source category
+    maps: HashMap<String, Map>,
+}
+
+impl Almanac {
+    fn mapped_seeds(&self, seeds: &RangeSet) -> Result<RangeSet> {
+        // start with seed map
+        let mut current_category = "seed";
+        let mut current = seeds.clone();
+        while current_category != "location" {
+            let m = self
+                .maps
+                .get(current_category)
+                .with_context(|| format!("no map from `{current_category}`"))?;
+            current = m.map(&current);
+            current_category = m.destination_category.as_str();
+        }
+        Ok(current)
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    ranges: Vec<Range>,
+    source_category: String,
+    destination_category: String,
+}
+
+impl Map {
+    fn map(&self, seeds: &RangeSet) -> RangeSet {
+        seeds.map_offsets(self.ranges.iter().map(|r| (r.source, r.offset)))
+    }
+}
+
+#[derive(Debug)]
+struct Range {
+    source: Interval,
+    offset: i64,
+}
+
+impl Range {
+    fn new(destination_start: i64, source_start: i64, length: i64) -> Self {
+        Self {
+            source: Interval::from_len(source_start, length),
+            offset: destination_start - source_start,
+        }
+    }
+}
+
+fn parse_range(line: &str) -> Result<Range> {
+    let nums = line
+        .split_whitespace()
+        .map(|d| d.parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("invalid range `{line}`"))?;
+    let [destination_start, source_start, length] = nums[..] else {
+        bail!("range `{line}` should have 3 numbers");
+    };
+    Ok(Range::new(destination_start, source_start, length))
+}
+
+fn parse_map(block: &str) -> Result<Map> {
+    let (name, rest) = block
+        .split_once(":\n")
+        .with_context(|| format!("map without ranges: `{block}`"))?;
+    let (source, destination) = name
+        .split_whitespace()
+        .next()
+        .and_then(|name| name.split_once("-to-"))
+        .with_context(|| format!("invalid map name `{name}`"))?;
+    let ranges = rest.lines().map(parse_range).collect::<Result<_>>()?;
+    Ok(Map {
+        source_category: source.to_string(),
+        destination_category: destination.to_string(),
+        ranges,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Almanac> {
+    let mut blocks = input.split("\n\n");
+
+    let seeds = blocks
+        .next()
+        .and_then(|block| block.strip_prefix("seeds:"))
+        .context("missing `seeds:` line")?
+        .split_whitespace()
+        .map(|d| d.parse::<i64>().with_context(|| format!("invalid seed `{d}`")))
+        .collect::<Result<_>>()?;
+
+    let mut maps = HashMap::new();
+    for block in blocks {
+        let map = parse_map(block)?;
+        maps.insert(map.source_category.clone(), map);
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn part_one(almanac: &Almanac) -> Result<i64> {
+    let seeds = almanac.seeds.iter().map(|&seed| Interval::from_len(seed, 1)).collect();
+    let mapped_seeds = almanac.mapped_seeds(&seeds)?;
+    mapped_seeds.min().ok_or(anyhow!("no seeds"))
+}
+
+pub fn part_two(almanac: &Almanac) -> Result<i64> {
+    let pairs = almanac.seeds.chunks_exact(2);
+    if let [seed] = pairs.remainder() {
+        bail!("seed {seed} has no range length");
+    }
+    let seeds = pairs.map(|pair| Interval::from_len(pair[0], pair[1])).collect();
+
+    let mapped_seeds = almanac.mapped_seeds(&seeds)?;
+    mapped_seeds.min().ok_or(anyhow!("no seeds"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
+        assert_eq!(
+            true,
+            result.is_ok(),
+            "part one failed: {}",
+            result.err().unwrap()
+        );
+        assert_eq!(result.unwrap(), 35);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
+        assert_eq!(
+            true,
+            result.is_ok(),
+            "part two failed: {}",
+            result.err().unwrap()
+        );
+        assert_eq!(result.unwrap(), 46);
+    }
+
+    #[test]
+    fn test_part_two_odd_seeds() {
+        let mut almanac = parse(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        almanac.seeds.push(7);
+        assert!(part_two(&almanac).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("seeds: 1 x").is_err());
+        assert!(parse("seeds: 1\n\nseed-to-soil map:\n1 2").is_err());
+        assert!(parse("seeds: 1\n\nseed map:\n1 2 3").is_err());
+        assert!(parse("1 2\n\nseed-to-soil map:\n1 2 3").is_err());
+    }
+}
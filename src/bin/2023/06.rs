@@ -1,5 +1,5 @@
 use anyhow::{Result};
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 #[derive(Debug)]
 struct Race {
@@ -1,7 +1,7 @@
 use anyhow::{bail, Error, Result};
 use std::{cmp::Ordering, collections::HashMap, str::FromStr};
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq)]
 
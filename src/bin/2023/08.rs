@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use advent_of_code::math;
+use anyhow::{anyhow, bail, Result};
+advent_of_code::solution!(2023, 8);
+
+#[derive(Debug)]
+enum Instruction {
+    Right,
+    Left,
+}
+
+impl Instruction {
+    fn from_char(c: char) -> Result<Self> {
+        let instruction = match c {
+            'R' => Self::Right,
+            'L' => Self::Left,
+            _ => bail!("invalid instruction char: {}", c),
+        };
+        Ok(instruction)
+    }
+}
+
+#[derive(Debug)]
+struct Node<'a> {
+    name: &'a str,
+    right: &'a str,
+    left: &'a str,
+}
+
+impl<'a> Node<'a> {
+    fn from_str(s: &'a str) -> Result<Self> {
+        let (n, pair) = s.split_once("=").ok_or(anyhow!("invalid node"))?;
+        let (left, right) = pair
+            .trim()
+            .trim_start_matches("(")
+            .trim_end_matches(")")
+            .split_once(",")
+            .ok_or(anyhow!("invalid node"))?;
+
+        Ok(Node {
+            name: n.trim(),
+            right: right.trim(),
+            left: left.trim(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Network<'a> {
+    instructions: Vec<Instruction>,
+    nodes: HashMap<&'a str, Node<'a>>,
+}
+
+fn parse_network(input: &str) -> Network {
+    let lines: Vec<_> = input.lines().collect();
+    let instructions = lines[0]
+        .chars()
+        .map(|c| Instruction::from_char(c).unwrap())
+        .collect();
+
+    let nodes = lines[2..]
+        .iter()
+        .map(|l| {
+            let node = Node::from_str(l).unwrap();
+            (node.name, node)
+        })
+        .collect();
+
+    Network {
+        instructions: instructions,
+        nodes: nodes,
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32> {
+    let network = parse_network(input);
+
+    let mut count = 0;
+    let mut current = "AAA";
+    for instruction in network.instructions.iter().cycle() {
+        let node = network.nodes.get(current).ok_or(anyhow!("missing node"))?;
+        current = match instruction {
+            Instruction::Right => node.right,
+            Instruction::Left => node.left,
+        };
+        count += 1;
+
+        if current == "ZZZ" {
+            break;
+        }
+    }
+
+    Ok(count)
+}
+
+/// The steps at which a ghost is on a `..Z` node. A ghost's next move only depends on its node and its position in
+/// the instructions, so its walk loops as soon as such a state repeats.
+struct GhostHits {
+    /// Hits before the walk enters its loop, which happen only once.
+    transient: Vec<u64>,
+    /// Hits in the first pass through the loop, which repeat every `period` steps.
+    looped: Vec<u64>,
+    loop_start: u64,
+    period: u64,
+}
+
+impl GhostHits {
+    fn contains(&self, step: u64) -> bool {
+        if step < self.loop_start {
+            self.transient.contains(&step)
+        } else {
+            self.looped.contains(&(self.loop_start + (step - self.loop_start) % self.period))
+        }
+    }
+}
+
+/// Walks a ghost from `start` until its walk loops, which takes at most nodes × instructions steps.
+fn ghost_hits(network: &Network, start: &str) -> Result<GhostHits> {
+    if network.instructions.is_empty() {
+        bail!("no instructions to follow");
+    }
+
+    let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+    let mut hits = vec![];
+    let mut current = start;
+    let mut step = 0;
+
+    loop {
+        let index = (step % network.instructions.len() as u64) as usize;
+        if let Some(&loop_start) = seen.get(&(current, index)) {
+            if hits.is_empty() {
+                bail!("ghost starting at {start} never reaches a `..Z` node");
+            }
+            let (transient, looped) = hits.into_iter().partition(|&hit| hit < loop_start);
+            return Ok(GhostHits {
+                transient,
+                looped,
+                loop_start,
+                period: step - loop_start,
+            });
+        }
+        seen.insert((current, index), step);
+
+        if current.ends_with('Z') {
+            hits.push(step);
+        }
+
+        let node = network.nodes.get(current).ok_or(anyhow!("missing node"))?;
+        current = match network.instructions[index] {
+            Instruction::Right => node.right,
+            Instruction::Left => node.left,
+        };
+        step += 1;
+    }
+}
+
+pub fn part_two(input: &str) -> Result<u64> {
+    let network = parse_network(input);
+
+    let ghosts = network
+        .nodes
+        .keys()
+        .filter(|s| s.ends_with('A'))
+        .map(|start| ghost_hits(&network, start))
+        .collect::<Result<Vec<_>>>()?;
+
+    // a step before the loop of some ghost can only be one of that ghost's transient hits, and is earlier than any
+    // step at which all ghosts are in their loops.
+    let transient = ghosts
+        .iter()
+        .flat_map(|ghost| ghost.transient.iter().copied())
+        .filter(|&step| ghosts.iter().all(|ghost| ghost.contains(step)))
+        .min();
+    if let Some(step) = transient {
+        return Ok(step);
+    }
+
+    // in its loop, a ghost sits on a `..Z` node at `hit + k * period` steps. Solve for a common step for every
+    // combination of hits.
+    let mut congruences = vec![(0_i64, 1_i64)];
+    for ghost in &ghosts {
+        let mut combined = vec![];
+        for &congruence in &congruences {
+            for &hit in &ghost.looped {
+                // combinations without a common step are skipped, an overflow is an error of its own.
+                if let Some(solution) = math::crt([congruence, (hit as i64, ghost.period as i64)])? {
+                    combined.push(solution);
+                }
+            }
+        }
+        congruences = combined;
+    }
+
+    let earliest = ghosts.iter().map(|ghost| ghost.loop_start).max().unwrap_or(0);
+    congruences
+        .into_iter()
+        .map(|(step, period)| {
+            let (step, period) = (step as u64, period as u64);
+            step + earliest.saturating_sub(step).div_ceil(period) * period
+        })
+        .min()
+        .ok_or(anyhow!("ghosts never reach `..Z` nodes at the same time"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_loops() -> Result<()> {
+        // `11` passes `11Z` every 3 steps from step 1, `22` passes `22Z` every 2 steps from step 2, and `33` passes
+        // `33Z` once before it gets stuck in a loop.
+        let network = |starts: &str| {
+            format!(
+                "L\n\n{starts}\n11Z = (11B, XXX)\n11B = (11C, XXX)\n11C = (11Z, XXX)\n22B = (22Z, XXX)\n22Z = (22B, XXX)\n\
+                 33Z = (33B, XXX)\n33B = (33B, XXX)\nXXX = (XXX, XXX)"
+            )
+        };
+
+        assert_eq!(part_two(&network("11A = (11Z, XXX)\n33A = (33Z, XXX)"))?, 1);
+        assert_eq!(part_two(&network("11A = (11Z, XXX)\n22A = (22B, XXX)"))?, 4);
+        assert!(part_two(&network("22A = (22B, XXX)\n33A = (33Z, XXX)")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_errors() {
+        assert!(part_two("LR\n\n11A = (11B, 11B)\n11B = (11A, 11A)").is_err());
+        assert!(part_two("\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)").is_err());
+    }
+}
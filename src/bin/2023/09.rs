@@ -1,5 +1,5 @@
 use anyhow::Result;
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 struct History {
     numbers: Vec<i64>,
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use advent_of_code::grid::{Coordinate, Direction, Grid};
+use anyhow::{anyhow, Result};
+advent_of_code::solution!(2023, 10);
+
+struct Maze {
+    tiles: Grid<Tile>,
+}
+
+impl Maze {
+    fn start(&self) -> Coordinate {
+        self.tiles.position(|&tile| tile == Tile::Start).expect("start tile")
+    }
+
+    fn neighbor_pipes(&self, c: Coordinate) -> Vec<Coordinate> {
+        match self.tiles[c] {
+            Tile::Start => Direction::ALL
+                .into_iter()
+                .filter(|&direction| {
+                    self.tiles
+                        .neighbor(c, direction)
+                        .is_some_and(|n| self.tiles[n].connects(direction.opposite()))
+                })
+                .filter_map(|direction| self.tiles.neighbor(c, direction))
+                .collect(),
+            tile => tile
+                .connections()
+                .iter()
+                .filter_map(|&direction| self.tiles.neighbor(c, direction))
+                .filter(|&n| self.tiles[n] != Tile::Ground)
+                .collect(),
+        }
+    }
+
+    fn search_from_start(&self) -> HashMap<Coordinate, usize> {
+        let mut to_search: VecDeque<(Coordinate, usize)> = VecDeque::from([(self.start(), 0)]);
+        let mut searched: HashMap<Coordinate, usize> = HashMap::new();
+
+        while !to_search.is_empty() {
+            let (current, distance) = to_search.pop_front().expect("non empty to search queue");
+            searched.insert(current, distance);
+
+            for neighbor in self.neighbor_pipes(current) {
+                if !searched.contains_key(&neighbor) {
+                    to_search.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+        searched
+    }
+
+    fn furthest_tile_distance(&self) -> usize {
+        *self
+            .search_from_start()
+            .values()
+            .max()
+            .expect("at least one tile")
+    }
+
+    fn enclosed_tiles(&self) -> usize {
+        // search main loop
+        let main_loop = self.search_from_start();
+
+        // replace all non main loop tiles with ground
+        let mut cleaned = self.tiles.clone();
+        for c in self.tiles.coordinates() {
+            if !main_loop.contains_key(&c) {
+                cleaned[c] = Tile::Ground;
+            }
+        }
+
+        // replace the start tile with his pipe equivalent
+        let start = self.start();
+        let start_neighbors = self.neighbor_pipes(start);
+        assert_eq!(2, start_neighbors.len());
+        cleaned[start] = start_pipe(start, start_neighbors);
+
+        // expand the grid into 2x scale, connecting pipes across the inserted rows and columns
+        let mut double_scale = Grid::new(cleaned.width() * 2 - 1, cleaned.height() * 2 - 1, Tile::Ground);
+        for (c, &tile) in cleaned.iter() {
+            double_scale[Coordinate::new(c.row * 2, c.column * 2)] = tile;
+            if c.column > 0 && tile.connects(Direction::West) {
+                double_scale[Coordinate::new(c.row * 2, c.column * 2 - 1)] = Tile::HorizontalPipe;
+            }
+            if c.row > 0 && tile.connects(Direction::North) {
+                double_scale[Coordinate::new(c.row * 2 - 1, c.column * 2)] = Tile::VerticalPipe;
+            }
+        }
+
+        // add the bottom and top row as well as the sides
+        let mut to_search: Vec<Coordinate> = Vec::from([]);
+        for column in 0..double_scale.width() {
+            if !main_loop.contains_key(&Coordinate::new(0, column / 2)) {
+                to_search.push(Coordinate::new(0, column));
+            }
+            if !main_loop.contains_key(&Coordinate::new(self.tiles.height() - 1, column / 2)) {
+                to_search.push(Coordinate::new(double_scale.height() - 1, column));
+            }
+        }
+        for row in 0..double_scale.height() {
+            if !main_loop.contains_key(&Coordinate::new(row / 2, 0)) {
+                to_search.push(Coordinate::new(row, 0));
+            }
+            if !main_loop.contains_key(&Coordinate::new(row / 2, self.tiles.width() - 1)) {
+                to_search.push(Coordinate::new(row, double_scale.width() - 1));
+            }
+        }
+
+        let mut searched: HashSet<Coordinate> = HashSet::new();
+        while !to_search.is_empty() {
+            let current = to_search.pop().expect("non empty to search queue");
+            searched.insert(current);
+
+            for neighbor in double_scale.neighbors(current) {
+                if double_scale[neighbor] == Tile::Ground && !searched.contains(&neighbor) {
+                    to_search.push(neighbor);
+                }
+            }
+        }
+
+        let outside: HashSet<_> = searched
+            .iter()
+            .filter_map(|s| {
+                if s.row % 2 == 0 && s.column % 2 == 0 {
+                    Some(Coordinate::new(s.row / 2, s.column / 2))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        (self.tiles.height() * self.tiles.width()) - (main_loop.len() + outside.len())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Tile {
+    VerticalPipe,
+    HorizontalPipe,
+    NorthEastBend,
+    NorthWestBend,
+    SouthWestBend,
+    SouthEastBend,
+    Ground,
+    Start,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Result<Self> {
+        let tile = match c {
+            '|' => Self::VerticalPipe,
+            '-' => Self::HorizontalPipe,
+            'L' => Self::NorthEastBend,
+            'J' => Self::NorthWestBend,
+            '7' => Self::SouthWestBend,
+            'F' => Self::SouthEastBend,
+            '.' => Self::Ground,
+            'S' => Self::Start,
+            _ => return Err(anyhow!("invalid char: {c}")),
+        };
+        Ok(tile)
+    }
+
+    fn connections(&self) -> &'static [Direction] {
+        match self {
+            Self::VerticalPipe => &[Direction::North, Direction::South],
+            Self::HorizontalPipe => &[Direction::East, Direction::West],
+            Self::NorthEastBend => &[Direction::North, Direction::East],
+            Self::NorthWestBend => &[Direction::North, Direction::West],
+            Self::SouthWestBend => &[Direction::South, Direction::West],
+            Self::SouthEastBend => &[Direction::East, Direction::South],
+            Self::Ground | Self::Start => &[],
+        }
+    }
+
+    fn connects(&self, direction: Direction) -> bool {
+        self.connections().contains(&direction)
+    }
+
+    fn to_char(&self) -> char {
+        return match self {
+            Self::VerticalPipe => '│',
+            Self::HorizontalPipe => '─',
+            Self::NorthEastBend => '└',
+            Self::NorthWestBend => '┘',
+            Self::SouthWestBend => '┐',
+            Self::SouthEastBend => '┌',
+            Self::Ground => '*',
+            Self::Start => 'S',
+        };
+    }
+}
+
+fn start_pipe(start: Coordinate, start_neighbors: Vec<Coordinate>) -> Tile {
+    let mut directions = start_neighbors
+        .iter()
+        .map(|n| {
+            if start.row < n.row {
+                Direction::South
+            } else if start.row > n.row {
+                Direction::North
+            } else if start.column < n.column {
+                Direction::East
+            } else {
+                Direction::West
+            }
+        })
+        .collect::<Vec<_>>();
+    directions.sort();
+
+    [
+        Tile::VerticalPipe,
+        Tile::HorizontalPipe,
+        Tile::NorthEastBend,
+        Tile::NorthWestBend,
+        Tile::SouthWestBend,
+        Tile::SouthEastBend,
+    ]
+    .into_iter()
+    .find(|tile| tile.connections() == directions.as_slice())
+    .expect("start connects to two pipes")
+}
+
+fn parse_grid(input: &str) -> Result<Maze> {
+    Ok(Maze {
+        tiles: Grid::try_parse(input, Tile::from_char)?,
+    })
+}
+
+pub fn part_one(input: &str) -> Result<usize> {
+    let grid = parse_grid(input)?;
+    Ok(grid.furthest_tile_distance())
+}
+
+pub fn part_two(input: &str) -> Result<usize> {
+    let grid = parse_grid(input)?;
+    Ok(grid.enclosed_tiles())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 70);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 8);
+        Ok(())
+    }
+}
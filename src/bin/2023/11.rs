@@ -0,0 +1,163 @@
+use std::vec;
+
+use advent_of_code::grid::{Coordinate, Grid};
+use advent_of_code::search;
+use anyhow::{anyhow, Result};
+advent_of_code::solution!(2023, 11);
+
+struct Image {
+    tiles: Grid<Tile>,
+
+    expanse_factor: usize,
+    expanse_rows: Vec<usize>,
+    expanse_columns: Vec<usize>,
+}
+
+impl Image {
+    fn from_tiles(tiles: Grid<Tile>, expanse_factor: usize) -> Self {
+        let mut expanse_rows = vec![];
+        for (i, row) in tiles.rows().enumerate() {
+            if row.iter().all(|&t| t == Tile::EmptySpace) {
+                expanse_rows.push(i);
+            }
+        }
+
+        let mut expanse_columns = vec![];
+        for (i, mut column) in tiles.columns().enumerate() {
+            if column.all(|&t| t == Tile::EmptySpace) {
+                expanse_columns.push(i);
+            }
+        }
+
+        Image {
+            tiles: tiles,
+            expanse_factor: expanse_factor,
+            expanse_rows: expanse_rows,
+            expanse_columns: expanse_columns,
+        }
+    }
+
+    fn galaxy_distances(&self) -> Vec<usize> {
+        let galaxies = self.galaxies();
+        let mut distances: Vec<usize> = vec![];
+        for (g1, &start) in galaxies.iter().enumerate() {
+            for (g2, &end) in galaxies.iter().enumerate() {
+                if g1 == g2 || g2 < g1 {
+                    continue;
+                }
+
+                let distance = self.distance(start, end);
+                distances.push(distance);
+            }
+        }
+        distances
+    }
+
+    fn galaxies(&self) -> Vec<Coordinate> {
+        let mut galaxies = vec![];
+        for (coordinate, tile) in self.tiles.iter() {
+            match tile {
+                Tile::Galaxy => galaxies.push(coordinate),
+                Tile::EmptySpace => {}
+            }
+        }
+        galaxies
+    }
+
+    fn distance(&self, start: Coordinate, end: Coordinate) -> usize {
+        search::astar(
+            [start],
+            |&current| self.tiles.neighbors(current).map(move |n| (n, self.cost(&current))),
+            |c| c.manhattan_distance(end),
+            |&c| c == end,
+        )
+        .expect("galaxies are connected")
+        .cost
+    }
+
+    fn cost(&self, from: &Coordinate) -> usize {
+        if self.expanse_rows.contains(&from.row) {
+            return self.expanse_factor;
+        }
+        if self.expanse_columns.contains(&from.column) {
+            return self.expanse_factor;
+        }
+        1
+    }
+
+    fn print(&self) {
+        println!(
+            "rows: {} columns: {}",
+            self.tiles.height(),
+            self.tiles.width()
+        );
+
+        println!(
+            "expanse rows: {:?} expanse columns: {:?}",
+            self.expanse_rows, self.expanse_columns
+        );
+
+        for row in self.tiles.rows() {
+            for tile in row {
+                match tile {
+                    Tile::EmptySpace => print!("."),
+                    Tile::Galaxy => print!("#"),
+                }
+            }
+            println!();
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Galaxy,
+    EmptySpace,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Result<Self> {
+        let tile = match c {
+            '#' => Self::Galaxy,
+            '.' => Self::EmptySpace,
+            _ => return Err(anyhow!("invalid char: {c}")),
+        };
+        Ok(tile)
+    }
+}
+
+fn parse_image(input: &str, expanse_factor: usize) -> Result<Image> {
+    let tiles = Grid::try_parse(input, Tile::from_char)?;
+    Ok(Image::from_tiles(tiles, expanse_factor))
+}
+
+pub fn part_one(input: &str) -> Result<usize> {
+    let image = parse_image(input, 2)?;
+    let distances = image.galaxy_distances();
+    Ok(distances.iter().sum())
+}
+
+pub fn part_two(input: &str) -> Result<usize> {
+    let image = parse_image(input, 1000000)?;
+    let distances = image.galaxy_distances();
+    Ok(distances.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 374);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 1030);
+        Ok(())
+    }
+}
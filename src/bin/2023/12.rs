@@ -4,7 +4,7 @@ use std::fmt::{Debug, Display};
 use std::vec;
 
 use anyhow::{anyhow, Ok, Result};
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
 enum Spring {
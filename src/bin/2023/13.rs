@@ -1,5 +1,5 @@
 use anyhow::Result;
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
 enum Reflection {
     Horizontal(usize),
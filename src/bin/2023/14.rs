@@ -1,12 +1,12 @@
-use std::collections::HashMap;
-
+use advent_of_code::cycle;
+use advent_of_code::grid::Grid;
 use anyhow::{anyhow, Ok, Result};
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2023, 14);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Platform {
-    grid: Vec<Vec<Tile>>,
+    grid: Grid<Tile>,
     view: View,
 }
 
@@ -43,16 +43,16 @@ impl Tile {
 }
 
 impl Platform {
-    fn from_rows(rows: Vec<Vec<Tile>>) -> Self {
+    fn from_grid(grid: Grid<Tile>) -> Self {
         Platform {
-            grid: rows,
+            grid,
             view: View::Rows,
         }
     }
 
     fn part_one(&mut self) -> usize {
         self.transpose();
-        for column in self.grid.iter_mut() {
+        for column in self.grid.rows_mut() {
             shift_left(column);
         }
         self.north_beam_load()
@@ -65,10 +65,10 @@ impl Platform {
         }
         debug_assert_eq!(View::Columns, self.view);
 
-        let num_rows = self.grid[0].len();
+        let num_rows = self.grid.width();
         let total = self
             .grid
-            .iter()
+            .rows()
             .flat_map(|column| {
                 column.iter().enumerate().map(|(i, &t)| {
                     if t == Tile::RoundedRock {
@@ -86,19 +86,19 @@ impl Platform {
     fn cycle(&mut self) {
         // north
         self.transpose();
-        for column in self.grid.iter_mut() {
+        for column in self.grid.rows_mut() {
             shift_left(column);
         }
 
         // west
         self.transpose();
-        for row in self.grid.iter_mut() {
+        for row in self.grid.rows_mut() {
             shift_left(row);
         }
 
         // south
         self.transpose();
-        for column in self.grid.iter_mut() {
+        for column in self.grid.rows_mut() {
             column.reverse();
             shift_left(column);
             column.reverse();
@@ -106,7 +106,7 @@ impl Platform {
 
         // east
         self.transpose();
-        for row in self.grid.iter_mut() {
+        for row in self.grid.rows_mut() {
             row.reverse();
             shift_left(row);
             row.reverse();
@@ -114,12 +114,7 @@ impl Platform {
     }
 
     fn transpose(&mut self) {
-        let rows = self.grid.len();
-        let cols = self.grid[0].len();
-
-        self.grid = (0..cols)
-            .map(|col| (0..rows).map(|row| self.grid[row][col]).collect())
-            .collect();
+        self.grid = self.grid.transpose();
 
         self.view = match self.view {
             View::Columns => View::Rows,
@@ -128,13 +123,13 @@ impl Platform {
     }
 
     fn print(&self) {
-        for row in &self.grid {
+        for row in self.grid.rows() {
             println!("{}", row.iter().map(|t| t.char()).collect::<String>());
         }
     }
 }
 
-fn shift_left(row: &mut Vec<Tile>) {
+fn shift_left(row: &mut [Tile]) {
     let mut empty_tile: Option<usize> = None;
     for i in 0..row.len() {
         match row[i] {
@@ -165,12 +160,7 @@ fn shift_left(row: &mut Vec<Tile>) {
 }
 
 fn parse_platform(input: &str) -> Result<Platform> {
-    Ok(Platform::from_rows(
-        input
-            .lines()
-            .map(|l| l.chars().map(|c| Tile::from_char(c)).collect())
-            .collect::<Result<Vec<_>>>()?,
-    ))
+    Ok(Platform::from_grid(Grid::try_parse(input, Tile::from_char)?))
 }
 
 pub fn part_one(input: &str) -> Result<usize> {
@@ -179,31 +169,15 @@ pub fn part_one(input: &str) -> Result<usize> {
 }
 
 pub fn part_two(input: &str) -> Result<usize> {
-    let mut platform = parse_platform(input)?;
-
-    let mut cache: HashMap<Platform, usize> = HashMap::new();
-    let mut states: Vec<Platform> = Vec::new();
-
-    let mut cycle_length = 0;
-    let mut cycle_start = 0;
-
-    // we should hit a cycle before the first 500k iterations
-    for i in 1..500_000 {
-        if let Some(&c) = cache.get(&platform) {
-            cycle_length = i - c;
-            cycle_start = c;
-            break;
-        }
-        let original = platform.clone();
-        platform.cycle();
-        cache.insert(original, i);
-        states.push(platform.clone());
-    }
+    let platform = parse_platform(input)?;
 
-    assert_ne!(0, cycle_start);
+    let spin = |p: &Platform| {
+        let mut next = p.clone();
+        next.cycle();
+        next
+    };
 
-    let moves_after_cycle = (1_000_000_000 - cycle_start) % cycle_length;
-    let mut platform = states[cycle_start - 1 + moves_after_cycle].clone();
+    let mut platform = cycle::nth_state(platform, spin, 1_000_000_000, 500_000)?.state;
     Ok(platform.north_beam_load())
 }
 
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
-advent_of_code::solution!(15);
+advent_of_code::solution!(2023, 15);
 
 #[derive(Debug, Clone)]
 struct Sequence1<'a> {
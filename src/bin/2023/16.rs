@@ -1,29 +1,17 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashSet, VecDeque};
 
+use advent_of_code::grid::{Coordinate, Direction, Grid};
 use anyhow::{Context, Result};
-advent_of_code::solution!(16);
+advent_of_code::solution!(2023, 16);
 
 #[derive(Debug, Clone)]
-struct Grid {
-    tiles: HashMap<Coordinate, Tile>,
-    rows: usize,
-    columns: usize,
+struct Contraption {
+    tiles: Grid<Tile>,
 }
 
-impl Grid {
-    fn new(tiles: HashMap<Coordinate, Tile>) -> Self {
-        let rows = tiles.iter().map(|(c, _)| c.y).max().unwrap() + 1;
-        let columns = tiles.iter().map(|(c, _)| c.x).max().unwrap() + 1;
-
-        Self {
-            tiles: tiles,
-            rows: rows,
-            columns: columns,
-        }
-    }
-
+impl Contraption {
     fn propagate(&mut self) {
-        self.propagate_from(Coordinate { x: 0, y: 0 }, Direction::East);
+        self.propagate_from(Coordinate::new(0, 0), Direction::East);
     }
 
     fn propagate_from(&mut self, coordinate: Coordinate, direction: Direction) {
@@ -42,7 +30,7 @@ impl Grid {
                 queue.push_back(next);
             }
             visited.insert((coordinate, direction));
-            self.tiles.get_mut(&coordinate).unwrap().energized = true;
+            self.tiles[coordinate].energized = true;
         }
     }
 
@@ -52,42 +40,39 @@ impl Grid {
         direction: &Direction,
     ) -> Vec<(Coordinate, Direction)> {
         let mut next = Vec::new();
-        let tile = self
-            .tiles
-            .get(&coordinate)
-            .expect(format!("No tile at {:?}", coordinate).as_str());
+        let tile = self.tiles[*coordinate];
         match tile.kind {
             TileKind::Empty => {
-                if let Some(neighbor) = self.neighbor(&coordinate, direction) {
+                if let Some(neighbor) = self.tiles.neighbor(*coordinate, *direction) {
                     next.push((neighbor, *direction));
                 }
             }
             TileKind::VeriticalSplitter => match direction {
                 Direction::East | Direction::West => {
-                    if let Some(neighbor) = self.neighbor(&coordinate, &Direction::North) {
+                    if let Some(neighbor) = self.tiles.neighbor(*coordinate, Direction::North) {
                         next.push((neighbor, Direction::North));
                     }
-                    if let Some(neighbor) = self.neighbor(&coordinate, &Direction::South) {
+                    if let Some(neighbor) = self.tiles.neighbor(*coordinate, Direction::South) {
                         next.push((neighbor, Direction::South));
                     }
                 }
                 Direction::North | Direction::South => {
-                    if let Some(neighbor) = self.neighbor(&coordinate, direction) {
+                    if let Some(neighbor) = self.tiles.neighbor(*coordinate, *direction) {
                         next.push((neighbor, *direction));
                     }
                 }
             },
             TileKind::HorizontalSplitter => match direction {
                 Direction::North | Direction::South => {
-                    if let Some(neighbor) = self.neighbor(&coordinate, &Direction::East) {
+                    if let Some(neighbor) = self.tiles.neighbor(*coordinate, Direction::East) {
                         next.push((neighbor, Direction::East));
                     }
-                    if let Some(neighbor) = self.neighbor(&coordinate, &Direction::West) {
+                    if let Some(neighbor) = self.tiles.neighbor(*coordinate, Direction::West) {
                         next.push((neighbor, Direction::West));
                     }
                 }
                 Direction::East | Direction::West => {
-                    if let Some(neighbor) = self.neighbor(&coordinate, direction) {
+                    if let Some(neighbor) = self.tiles.neighbor(*coordinate, *direction) {
                         next.push((neighbor, *direction));
                     }
                 }
@@ -100,7 +85,7 @@ impl Grid {
                     Direction::South => Direction::West,
                     Direction::West => Direction::South,
                 };
-                if let Some(neighbor) = self.neighbor(&coordinate, &reflection) {
+                if let Some(neighbor) = self.tiles.neighbor(*coordinate, reflection) {
                     next.push((neighbor, reflection));
                 }
             }
@@ -112,7 +97,7 @@ impl Grid {
                     Direction::South => Direction::East,
                     Direction::West => Direction::North,
                 };
-                if let Some(neighbor) = self.neighbor(&coordinate, &reflection) {
+                if let Some(neighbor) = self.tiles.neighbor(*coordinate, reflection) {
                     next.push((neighbor, reflection));
                 }
             }
@@ -120,52 +105,13 @@ impl Grid {
         next
     }
 
-    fn neighbor(&self, coordinate: &Coordinate, direction: &Direction) -> Option<Coordinate> {
-        match direction {
-            Direction::North => {
-                if coordinate.y > 0 {
-                    return Some(Coordinate {
-                        x: coordinate.x,
-                        y: coordinate.y - 1,
-                    });
-                }
-            }
-            Direction::East => {
-                if coordinate.x < self.columns - 1 {
-                    return Some(Coordinate {
-                        x: coordinate.x + 1,
-                        y: coordinate.y,
-                    });
-                }
-            }
-            Direction::South => {
-                if coordinate.y < self.rows - 1 {
-                    return Some(Coordinate {
-                        x: coordinate.x,
-                        y: coordinate.y + 1,
-                    });
-                }
-            }
-            Direction::West => {
-                if coordinate.x > 0 {
-                    return Some(Coordinate {
-                        x: coordinate.x - 1,
-                        y: coordinate.y,
-                    });
-                }
-            }
-        }
-        None
-    }
-
     fn num_energized(&self) -> usize {
-        self.tiles.values().filter(|tile| tile.energized).count()
+        self.tiles.iter().filter(|(_, tile)| tile.energized).count()
     }
 
     fn print(&self) {
-        for y in 0..self.rows {
-            for x in 0..self.columns {
-                let tile = self.tiles.get(&Coordinate { x, y }).unwrap();
+        for row in self.tiles.rows() {
+            for tile in row {
                 if tile.energized {
                     print!("E");
                 } else {
@@ -177,12 +123,6 @@ impl Grid {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Coordinate {
-    x: usize,
-    y: usize,
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Tile {
     kind: TileKind,
@@ -212,33 +152,15 @@ impl TileKind {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-fn parse_grid(input: &str) -> Result<Grid> {
-    let tiles = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row, line)| {
-            line.chars().enumerate().map(move |(column, c)| {
-                let coordinate = Coordinate { x: column, y: row };
-                Ok((
-                    coordinate,
-                    Tile {
-                        kind: TileKind::from_char(c)?,
-                        energized: false,
-                    },
-                ))
-            })
+fn parse_grid(input: &str) -> Result<Contraption> {
+    let tiles = Grid::try_parse(input, |c| {
+        Ok::<_, anyhow::Error>(Tile {
+            kind: TileKind::from_char(c)?,
+            energized: false,
         })
-        .collect::<Result<HashMap<_, _>>>()?;
+    })?;
 
-    Ok(Grid::new(tiles))
+    Ok(Contraption { tiles })
 }
 
 pub fn part_one(input: &str) -> Result<usize> {
@@ -251,35 +173,23 @@ pub fn part_two(input: &str) -> Result<usize> {
     let grid = parse_grid(input)?;
 
     let mut best = 0;
-    for row in 0..grid.rows {
+    for row in 0..grid.tiles.height() {
         let mut right = grid.clone();
-        right.propagate_from(Coordinate { x: 0, y: row }, Direction::East);
+        right.propagate_from(Coordinate::new(row, 0), Direction::East);
         best = best.max(right.num_energized());
 
         let mut left = grid.clone();
-        left.propagate_from(
-            Coordinate {
-                x: left.columns - 1,
-                y: row,
-            },
-            Direction::West,
-        );
+        left.propagate_from(Coordinate::new(row, left.tiles.width() - 1), Direction::West);
         best = best.max(left.num_energized());
     }
 
-    for column in 0..grid.columns {
+    for column in 0..grid.tiles.width() {
         let mut top = grid.clone();
-        top.propagate_from(Coordinate { x: column, y: 0 }, Direction::South);
+        top.propagate_from(Coordinate::new(0, column), Direction::South);
         best = best.max(top.num_energized());
 
         let mut bottom = grid.clone();
-        bottom.propagate_from(
-            Coordinate {
-                x: column,
-                y: bottom.rows - 1,
-            },
-            Direction::North,
-        );
+        bottom.propagate_from(Coordinate::new(bottom.tiles.height() - 1, column), Direction::North);
         best = best.max(bottom.num_energized());
     }
     Ok(best)
@@ -0,0 +1,126 @@
+use advent_of_code::grid::{Coordinate, Direction, Grid};
+use advent_of_code::search;
+use anyhow::{anyhow, Result};
+advent_of_code::solution!(2023, 17);
+
+#[derive(Debug, Clone)]
+struct City {
+    blocks: Grid<u32>,
+}
+
+impl City {
+    fn search<F>(&self, neighbors: F) -> Option<u32>
+    where
+        F: Fn(&Self, &SearchState) -> Vec<(SearchState, u32)>,
+    {
+        let start = Coordinate::new(0, 0);
+        let goal = Coordinate::new(self.blocks.height() - 1, self.blocks.width() - 1);
+
+        let starts = [SearchState::new_start(start, Direction::South), SearchState::new_start(start, Direction::East)];
+        let result = search::dijkstra(starts, |state| neighbors(self, state), |state| state.current == goal)?;
+        Some(result.cost)
+    }
+
+    fn neighbors(&self, state: &SearchState) -> Vec<(SearchState, u32)> {
+        let mut neighbors = Vec::new();
+
+        for orthogonal in state.direction.orthogonal() {
+            if let Some(next) = self.blocks.neighbor(state.current, orthogonal) {
+                neighbors.push((state.new_turn(next, orthogonal), self.blocks[next]));
+            }
+        }
+
+        if state.num_straight_steps < 3 {
+            if let Some(next) = self.blocks.neighbor(state.current, state.direction) {
+                neighbors.push((state.new_straight(next), self.blocks[next]));
+            }
+        }
+        neighbors
+    }
+
+    fn neighbors_ultra(&self, state: &SearchState) -> Vec<(SearchState, u32)> {
+        let mut neighbors = Vec::new();
+
+        if state.num_straight_steps >= 4 {
+            for orthogonal in state.direction.orthogonal() {
+                if let Some(next) = self.blocks.neighbor(state.current, orthogonal) {
+                    neighbors.push((state.new_turn(next, orthogonal), self.blocks[next]));
+                }
+            }
+        }
+
+        if state.num_straight_steps < 10 {
+            if let Some(next) = self.blocks.neighbor(state.current, state.direction) {
+                neighbors.push((state.new_straight(next), self.blocks[next]));
+            }
+        }
+        neighbors
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SearchState {
+    current: Coordinate,
+    direction: Direction,
+    num_straight_steps: u32,
+}
+
+impl SearchState {
+    fn new_start(coordinate: Coordinate, direction: Direction) -> SearchState {
+        Self {
+            current: coordinate,
+            direction,
+            num_straight_steps: 2,
+        }
+    }
+
+    fn new_straight(&self, next: Coordinate) -> Self {
+        Self {
+            current: next,
+            direction: self.direction,
+            num_straight_steps: self.num_straight_steps + 1,
+        }
+    }
+
+    fn new_turn(&self, next: Coordinate, direction: Direction) -> Self {
+        Self {
+            current: next,
+            direction,
+            num_straight_steps: 1,
+        }
+    }
+}
+
+fn parse_grid(input: &str) -> Result<City> {
+    let blocks = Grid::try_parse(input, |c| c.to_digit(10).ok_or(anyhow!("invalid digit: {}", c)))?;
+    Ok(City { blocks })
+}
+
+pub fn part_one(input: &str) -> Result<u32> {
+    let grid = parse_grid(input)?;
+    grid.search(City::neighbors).ok_or(anyhow!("no path to the goal"))
+}
+
+pub fn part_two(input: &str) -> Result<u32> {
+    let grid = parse_grid(input)?;
+    grid.search(City::neighbors_ultra).ok_or(anyhow!("no path to the goal"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 102);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY))?;
+        assert_eq!(result, 94);
+        Ok(())
+    }
+}
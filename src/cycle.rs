@@ -0,0 +1,188 @@
+//! Cycle detection for puzzles that ask for the state after an enormous number of steps.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// A cycle in a sequence of states: the state at step `start` reoccurs every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Maps step `n` onto the equivalent step within the first pass through the cycle.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            return n;
+        }
+        self.start + (n - self.start) % self.length
+    }
+}
+
+/// The state at a requested step, together with the cycle used to extrapolate it.
+///
+/// `cycle` is [`None`] if the requested step was reached before any state repeated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NthState<S> {
+    pub state: S,
+    pub cycle: Option<Cycle>,
+}
+
+/// An error which can be returned when no cycle was found.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CycleError {
+    NotFound { max_steps: usize },
+}
+
+impl Error for CycleError {}
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CycleError::NotFound { max_steps } => write!(f, "no cycle found within {max_steps} steps."),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the state after applying `step` `n` times to `initial`.
+///
+/// Every visited state is hashed, so a cycle is detected the first time a state repeats.
+/// Simulates at most `max_steps` steps before giving up with [`CycleError::NotFound`].
+pub fn nth_state<S: Hash + Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> S, n: usize, max_steps: usize) -> Result<NthState<S>, CycleError> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = vec![];
+    let mut current = initial;
+
+    for i in 0..=max_steps {
+        if i == n {
+            return Ok(NthState { state: current, cycle: None });
+        }
+
+        match seen.entry(current.clone()) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                let cycle = Cycle { start, length: i - start };
+                return Ok(NthState {
+                    state: states.swap_remove(cycle.equivalent_step(n)),
+                    cycle: Some(cycle),
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(i);
+            }
+        }
+
+        let next = step(&current);
+        states.push(current);
+        current = next;
+    }
+
+    Err(CycleError::NotFound { max_steps })
+}
+
+/// Finds the first cycle in the sequence starting at `initial` with Brent's algorithm.
+///
+/// Unlike [`nth_state`], this only keeps a constant number of states in memory and does not require `S: Hash`,
+/// at the cost of simulating some steps multiple times.
+pub fn find_cycle_brent<S: Eq + Clone>(initial: &S, mut step: impl FnMut(&S) -> S, max_steps: usize) -> Result<Cycle, CycleError> {
+    let mut power = 1;
+    let mut length = 1;
+    let mut steps = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+
+    // find the cycle length by letting the hare run ahead in powers of two.
+    while tortoise != hare {
+        if steps >= max_steps {
+            return Err(CycleError::NotFound { max_steps });
+        }
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+        steps += 1;
+    }
+
+    // find the cycle start by moving two pointers `length` steps apart in lockstep.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Ok(Cycle { start, length })
+}
+
+/// Returns the state after applying `step` `n` times to `initial`, detecting the cycle with [`find_cycle_brent`].
+pub fn nth_state_brent<S: Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> S, n: usize, max_steps: usize) -> Result<NthState<S>, CycleError> {
+    let cycle = find_cycle_brent(&initial, &mut step, max_steps)?;
+
+    let mut state = initial;
+    for _ in 0..cycle.equivalent_step(n) {
+        state = step(&state);
+    }
+
+    Ok(NthState { state, cycle: Some(cycle) })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_cycle_brent, nth_state, nth_state_brent, Cycle, CycleError};
+
+    fn step(x: &u64) -> u64 {
+        (x * x + 1) % 1000
+    }
+
+    fn naive(n: usize) -> u64 {
+        (0..n).fold(3, |x, _| step(&x))
+    }
+
+    #[test]
+    fn hash_and_brent_agree() {
+        let hashed = nth_state(3, step, 1_000_000, 10_000).unwrap();
+        let brent = find_cycle_brent(&3, step, 10_000).unwrap();
+        assert_eq!(hashed.cycle, Some(brent));
+    }
+
+    #[test]
+    fn extrapolates_nth_state() {
+        for n in [0, 1, 5, 50, 123, 4567] {
+            assert_eq!(nth_state(3, step, n, 10_000).unwrap().state, naive(n));
+            assert_eq!(nth_state_brent(3, step, n, 10_000).unwrap().state, naive(n));
+        }
+    }
+
+    #[test]
+    fn cycle_from_first_state() {
+        let res = nth_state(0_u8, |x| (x + 1) % 4, 10, 100).unwrap();
+        assert_eq!(res.state, 2);
+        assert_eq!(res.cycle, Some(Cycle { start: 0, length: 4 }));
+
+        let res = nth_state(0_u32, |x| (x + 1) % 7, 1_000_000_000, 100).unwrap();
+        assert_eq!(res.state, 1_000_000_000 % 7);
+    }
+
+    #[test]
+    fn errors_without_cycle() {
+        assert_eq!(nth_state(0_u64, |x| x + 1, 1000, 100), Err(CycleError::NotFound { max_steps: 100 }));
+        assert_eq!(find_cycle_brent(&0_u64, |x| x + 1, 100), Err(CycleError::NotFound { max_steps: 100 }));
+    }
+}
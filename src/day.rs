@@ -2,8 +2,12 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::Year;
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
+/// Events may have fewer days, see [`Year::last_day`].
+///
 /// # Display
 /// This value displays as a two digit number.
 ///
@@ -32,7 +36,7 @@ impl Day {
     }
 
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
@@ -80,20 +84,23 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of the event of `year`, from the 1st to its [last day](Year::last_day).
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of an event, from the 1st to its [last day](Year::last_day).
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.last_day().into_inner(),
+        }
     }
 }
 
@@ -101,10 +108,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day, which is at most 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -114,6 +121,60 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days, parsed from a comma separated list of days and ranges.
+///
+/// Ranges are written like rust ranges, either inclusive (`3..=9`) or exclusive (`3..10`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<Day>);
+
+impl DaySelection {
+    pub fn contains(&self, day: Day) -> bool {
+        self.0.contains(&day)
+    }
+
+    /// The selected days, in ascending order and without duplicates.
+    pub fn days(&self) -> &[Day] {
+        &self.0
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |s: &str| s.trim().parse::<Day>().map_err(|_| DaySelectionFromStrError(s.trim().into()));
+        let mut days = vec![];
+
+        for item in s.split(',') {
+            if let Some((start, end)) = item.split_once("..=") {
+                days.extend((parse_day(start)?.0..=parse_day(end)?.0).map(Day));
+            } else if let Some((start, end)) = item.split_once("..") {
+                days.extend((parse_day(start)?.0..parse_day(end)?.0).map(Day));
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(Self(days))
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySelection`], holding the invalid day.
+#[derive(Debug)]
+pub struct DaySelectionFromStrError(String);
+
+impl Error for DaySelectionFromStrError {}
+
+impl Display for DaySelectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid day `{}`, expecting days between 1 and 25 or ranges like `3..=9`", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,11 +195,12 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySelection};
+    use crate::{day, year};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2023));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -167,6 +229,29 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_of_short_event() {
+        assert_eq!(all_days(year!(2025)).last(), Some(Day(12)));
+        assert_eq!(all_days(year!(2025)).count(), 12);
+    }
+
+    #[test]
+    fn parses_day_selections() {
+        let days = |s: &str| s.parse::<DaySelection>().map(|d| d.days().iter().map(|d| d.0).collect::<Vec<_>>()).ok();
+        assert_eq!(days("3..=6"), Some(vec![3, 4, 5, 6]));
+        assert_eq!(days("3..6"), Some(vec![3, 4, 5]));
+        assert_eq!(days("9, 1,3"), Some(vec![1, 3, 9]));
+        assert_eq!(days("2..=4,3,25"), Some(vec![2, 3, 4, 25]));
+        assert_eq!(days("6..=3"), Some(vec![]));
+        assert_eq!(days("0..=3"), None);
+        assert_eq!(days("1,26"), None);
+        assert_eq!(days("a"), None);
+
+        let days: DaySelection = "1,3..=5,10..12".parse().unwrap();
+        assert!(days.contains(day!(4)));
+        assert!(!days.contains(day!(12)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
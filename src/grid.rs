@@ -0,0 +1,404 @@
+//! Dense, row-major two-dimensional grid shared by the solutions.
+
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// A position in a [`Grid`], addressed by row and column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coordinate {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Coordinate {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// Returns the coordinate shifted by the given row and column delta,
+    /// or [`None`] if the result would be negative.
+    pub fn offset(self, row_delta: isize, column_delta: isize) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(row_delta)?,
+            column: self.column.checked_add_signed(column_delta)?,
+        })
+    }
+
+    /// Returns the coordinate one step into `direction`, or [`None`] if it would be negative.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (row_delta, column_delta) = direction.delta();
+        self.offset(row_delta, column_delta)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+}
+
+impl Display for Coordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.column)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four cardinal directions. North points towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting north.
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// The `(row, column)` delta of a single step into this direction.
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+
+    pub const fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub const fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    /// The two directions perpendicular to this one.
+    pub const fn orthogonal(self) -> [Self; 2] {
+        [self.turn_left(), self.turn_right()]
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+const NEIGHBOR_DELTAS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A rectangular grid of cells stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid from a list of rows. All rows need to have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            if cells_in_row.len() != width {
+                return Err(GridError::RaggedRow {
+                    row,
+                    expected: width,
+                    actual: cells_in_row.len(),
+                });
+            }
+            cells.extend(cells_in_row);
+        }
+
+        Ok(Self { cells, width, height })
+    }
+
+    /// Parses a grid from text, mapping every character with `f`. Each line of `input` becomes one row.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Self::try_parse(input, |c| Ok::<T, GridError>(f(c)))
+    }
+
+    /// Parses a grid from text like [`Grid::parse`], with a fallible mapping function.
+    pub fn try_parse<E: From<GridError>>(input: &str, mut f: impl FnMut(char) -> Result<T, E>) -> Result<Self, E> {
+        let rows = input.lines().map(|line| line.chars().map(&mut f).collect::<Result<Vec<_>, E>>()).collect::<Result<Vec<_>, E>>()?;
+        Ok(Self::from_rows(rows)?)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks whether `coordinate` lies within the bounds of the grid.
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        coordinate.row < self.height && coordinate.column < self.width
+    }
+
+    fn index_of(&self, coordinate: Coordinate) -> Option<usize> {
+        self.contains(coordinate).then(|| coordinate.row * self.width + coordinate.column)
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        self.index_of(coordinate).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        self.index_of(coordinate).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces the value at `coordinate` and returns the previous one.
+    pub fn set(&mut self, coordinate: Coordinate, value: T) -> Result<T, GridError> {
+        let cell = self.get_mut(coordinate).ok_or(GridError::OutOfBounds(coordinate))?;
+        Ok(std::mem::replace(cell, value))
+    }
+
+    /// Returns the neighbouring coordinate into `direction` if it lies within the grid.
+    pub fn neighbor(&self, coordinate: Coordinate, direction: Direction) -> Option<Coordinate> {
+        coordinate.step(direction).filter(|c| self.contains(*c))
+    }
+
+    /// Iterates over the (up to four) orthogonally adjacent coordinates that lie within the grid.
+    pub fn neighbors(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| self.neighbor(coordinate, d))
+    }
+
+    /// Iterates over the (up to eight) orthogonally and diagonally adjacent coordinates that lie within the grid.
+    pub fn neighbors_diagonal(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        NEIGHBOR_DELTAS_8
+            .into_iter()
+            .filter_map(move |(row_delta, column_delta)| coordinate.offset(row_delta, column_delta))
+            .filter(|c| self.contains(*c))
+    }
+
+    /// Iterates over all coordinates of the grid in row-major order.
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Coordinate::new(row, column)))
+    }
+
+    /// Iterates over all cells together with their coordinate in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.coordinates().zip(self.cells.iter())
+    }
+
+    /// Returns the coordinate of the first cell matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coordinate> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(c, _)| c)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics for a zero width.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    /// Iterates over the cells of a column from top to bottom, returns [`None`] if the column is out of bounds.
+    pub fn column(&self, column: usize) -> Option<impl DoubleEndedIterator<Item = &T>> {
+        (column < self.width).then(|| self.cells.iter().skip(column).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).filter_map(|column| self.column(column))
+    }
+
+    /// Creates a new grid by applying `f` to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Builds a grid of the given dimensions where each cell is copied from the coordinate returned by `source`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Coordinate) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(|(row, column)| self[source(row, column)].clone())
+            .collect();
+        Self { cells, width, height }
+    }
+
+    /// Swaps rows and columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |row, column| Coordinate::new(column, row))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |row, column| Coordinate::new(height - 1 - column, row))
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise.
+    #[must_use]
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |row, column| Coordinate::new(column, width - 1 - row))
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.get(coordinate)
+            .unwrap_or_else(|| panic!("coordinate {coordinate} out of bounds for grid of size {}x{}", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coordinate)
+            .unwrap_or_else(|| panic!("coordinate {coordinate} out of bounds for grid of size {width}x{height}"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when constructing or updating a [`Grid`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    RaggedRow { row: usize, expected: usize, actual: usize },
+    OutOfBounds(Coordinate),
+}
+
+impl Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::RaggedRow { row, expected, actual } => {
+                write!(f, "row {row} has {actual} cells, expecting {expected}.")
+            }
+            GridError::OutOfBounds(coordinate) => write!(f, "coordinate {coordinate} is out of bounds."),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Coordinate, Direction, Grid, GridError};
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456", |c| c.to_digit(10).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = digits();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(2).unwrap().copied().collect::<Vec<_>>(), vec![3, 6]);
+    }
+
+    #[test]
+    fn parses_cells() {
+        let grid = Grid::parse("#.\n.#", |c| c == '#').unwrap();
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.get(Coordinate::new(1, 1)), Some(&true));
+        assert_eq!(grid.get(Coordinate::new(0, 1)), Some(&false));
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let res = Grid::parse("12\n3", |c| c);
+        assert_eq!(res, Err(GridError::RaggedRow { row: 1, expected: 2, actual: 1 }));
+    }
+
+    #[test]
+    fn bounds_checked_access() {
+        let mut grid = digits();
+        assert_eq!(grid.get(Coordinate::new(0, 3)), None);
+        assert_eq!(grid.set(Coordinate::new(1, 1), 0), Ok(5));
+        assert_eq!(grid[Coordinate::new(1, 1)], 0);
+        assert_eq!(grid.set(Coordinate::new(2, 0), 0), Err(GridError::OutOfBounds(Coordinate::new(2, 0))));
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = digits();
+        let corner: Vec<_> = grid.neighbors(Coordinate::new(0, 0)).collect();
+        assert_eq!(corner, vec![Coordinate::new(0, 1), Coordinate::new(1, 0)]);
+        assert_eq!(grid.neighbors_diagonal(Coordinate::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors_diagonal(Coordinate::new(1, 1)).count(), 5);
+        assert_eq!(grid.neighbor(Coordinate::new(1, 2), Direction::East), None);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = digits();
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+}
@@ -1,4 +1,11 @@
+pub mod cycle;
 mod day;
+pub mod grid;
+pub mod math;
+pub mod range_set;
+pub mod search;
 pub mod template;
+mod year;
 
 pub use day::*;
+pub use year::*;
@@ -1,55 +1,165 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{all, compare, download, examples, read, scaffold, solve, verify};
+use advent_of_code::template::solution::Registry;
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
+    use std::time::Duration;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::runner::{BenchConfig, OutputFormat};
+    use advent_of_code::{scaffolded_years, Day, DaySelection, PuzzleId, Year};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            id: PuzzleId,
+        },
+        Examples {
+            id: PuzzleId,
+            release: bool,
         },
         Read {
-            day: Day,
+            id: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            id: PuzzleId,
+            template: Option<String>,
+            output_type: Option<String>,
+            force: bool,
+            download: bool,
         },
         Solve {
-            day: Day,
+            id: PuzzleId,
             release: bool,
             time: bool,
             submit: Option<u8>,
+            force: bool,
+            format: Option<OutputFormat>,
+            bench: BenchConfig,
+            input: Option<String>,
+            in_process: bool,
+            timeout: Option<Duration>,
         },
         All {
+            years: Vec<Year>,
+            days: Option<DaySelection>,
             release: bool,
             time: bool,
+            bench: BenchConfig,
+            jobs: Option<usize>,
+            in_process: bool,
+            timeout: Option<Duration>,
+        },
+        Compare {
+            threshold: Option<f64>,
+            baseline: Option<String>,
+            year: Option<Year>,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            release: bool,
         },
     }
 
+    fn default_year() -> Result<Year, Box<dyn std::error::Error>> {
+        Year::from_env().ok_or_else(|| "no year specified, pass `--year <year>` or set AOC_YEAR in .cargo/config.toml".into())
+    }
+
+    /// Reads `--year`, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => default_year(),
+        }
+    }
+
+    fn parse_puzzle(args: &mut pico_args::Arguments) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        let day: Day = args.free_from_str()?;
+        PuzzleId::checked(year, day).ok_or_else(|| format!("day {day} is not part of {year}, the event only has {} days", year.last_day()).into())
+    }
+
+    /// Like [`parse_year`], but `--year all` selects every year with solutions in `src/bin`.
+    fn parse_years(args: &mut pico_args::Arguments) -> Result<Vec<Year>, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str::<_, String>("--year")?.as_deref() {
+            Some("all") => Ok(scaffolded_years()),
+            Some(year) => Ok(vec![year.parse()?]),
+            None => Ok(vec![default_year()?]),
+        }
+    }
+
+    /// Reads `--timeout <seconds>`, which can't be combined with `--in-process`: a solution running in this process
+    /// can't be stopped.
+    fn parse_timeout(args: &mut pico_args::Arguments, in_process: bool) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        let timeout = args.opt_value_from_fn("--timeout", |s| {
+            let seconds: f64 = s.parse().map_err(|_| format!("invalid timeout {s:?}, expected a number of seconds"))?;
+            Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid timeout {s:?}, expected a positive number of seconds"))
+        })?;
+
+        if timeout.is_some() && in_process {
+            return Err("`--timeout` can't be combined with `--in-process`, solutions running in-process can't be stopped".into());
+        }
+        Ok(timeout)
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+            Some("all") => {
+                let in_process = args.contains("--in-process");
+                AppArguments::All {
+                    years: parse_years(&mut args)?,
+                    days: args.opt_value_from_str("--days")?,
+                    release: args.contains("--release"),
+                    time: args.contains("--time"),
+                    bench: BenchConfig::parse(&mut args)?,
+                    jobs: args.opt_value_from_str(["-j", "--jobs"])?,
+                    in_process,
+                    timeout: parse_timeout(&mut args, in_process)?,
+                }
+            }
+            Some("compare") => AppArguments::Compare {
+                threshold: args.opt_value_from_str("--threshold")?,
+                baseline: args.opt_value_from_str("--baseline")?,
+                year: args.opt_value_from_str("--year")?,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                id: parse_puzzle(&mut args)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                release: args.contains("--release"),
+                id: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                id: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                template: args.opt_value_from_str("--template")?,
+                output_type: args.opt_value_from_str("--type")?,
+                force: args.contains("--force"),
+                download: args.contains("--download"),
+                id: parse_puzzle(&mut args)?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+            Some("solve") => {
+                let in_process = args.contains("--in-process");
+                AppArguments::Solve {
+                    id: parse_puzzle(&mut args)?,
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    force: args.contains("--force"),
+                    time: args.contains("--time"),
+                    format: args.opt_value_from_str("--format")?,
+                    bench: BenchConfig::parse(&mut args)?,
+                    input: args.opt_value_from_str("--input")?,
+                    in_process,
+                    timeout: parse_timeout(&mut args, in_process)?,
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                day: args.opt_free_from_str()?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -70,6 +180,28 @@ mod args {
     }
 }
 
+/// Every solution in `src/bin`, compiled into this binary to run them in-process. Generated by `build.rs`.
+#[cfg(feature = "in_process")]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// The solutions to run in-process if `--in-process` was passed.
+fn registry(in_process: bool) -> Option<&'static Registry> {
+    if !in_process {
+        return None;
+    }
+
+    #[cfg(feature = "in_process")]
+    return Some(&solutions::REGISTRY);
+
+    #[cfg(not(feature = "in_process"))]
+    {
+        eprintln!("Error: `--in-process` needs the solutions compiled into this binary, build it with `--features in_process`.");
+        std::process::exit(1);
+    }
+}
+
 fn main() {
     match parse() {
         Err(err) => {
@@ -77,16 +209,40 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                years,
+                days,
+                release,
+                time,
+                bench,
+                jobs,
+                in_process,
+                timeout,
+            } => all::handle(&years, days.as_ref(), release, time, bench, jobs, registry(in_process), timeout),
+            AppArguments::Compare { threshold, baseline, year } => compare::handle(threshold, baseline.as_deref(), year),
+            AppArguments::Download { id } => download::handle(id),
+            AppArguments::Examples { id, release } => examples::handle(id, release),
+            AppArguments::Verify { year, day, release } => verify::handle(year, day, release),
+            AppArguments::Read { id } => read::handle(id),
+            AppArguments::Scaffold {
+                id,
+                template,
+                output_type,
+                force,
+                download,
+            } => scaffold::handle(id, template.as_deref(), output_type.as_deref(), force, download),
             AppArguments::Solve {
-                day,
+                id,
                 release,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                force,
+                format,
+                bench,
+                input,
+                in_process,
+                timeout,
+            } => solve::handle(id, release, time, submit, force, format, bench, input, registry(in_process), timeout),
         },
     };
 }
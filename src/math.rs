@@ -0,0 +1,216 @@
+//! Number theory helpers. Operations that can overflow report it instead of wrapping.
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// Greatest common divisor of `a` and `b`. `gcd(0, 0)` is `0`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple of `a` and `b`, or [`None`] if it does not fit into an [`u64`].
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Greatest common divisor of all numbers. Returns `0` for an empty input.
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of all numbers, or [`None`] if it overflows. Returns `1` for an empty input.
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+/// Extended Euclidean algorithm. Returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_i128(a.into(), b.into());
+    let (g, x, y) = if g < 0 { (-g, -x, -y) } else { (g, x, y) };
+
+    // NOTE: bézout coefficients are bounded by `|a| / g` and `|b| / g`, so only `gcd(i64::MIN, 0)` can truncate.
+    #[allow(clippy::cast_possible_truncation)]
+    (g as i64, x as i64, y as i64)
+}
+
+/// Multiplicative inverse of `a` modulo `m` in the range `0..m`, or [`None`] if `a` and `m` are not coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Computes `base^exp mod m` by repeated squaring.
+#[allow(clippy::cast_possible_truncation)]
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    if m == 1 {
+        return 0;
+    }
+    let m = u128::from(m);
+    let mut base = u128::from(base) % m;
+    let mut result = 1;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+
+    // NOTE: the result is reduced modulo `m`, which fits into an u64.
+    result as u64
+}
+
+/// Why [`crt`] could not combine a system of congruences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    NonPositiveModulus(i64),
+    Overflow,
+}
+
+impl Error for CrtError {}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrtError::NonPositiveModulus(modulus) => write!(f, "modulus {modulus} is not positive."),
+            CrtError::Overflow => write!(f, "combined modulus does not fit into an i64."),
+        }
+    }
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` with the Chinese Remainder Theorem.
+///
+/// Moduli do not need to be coprime. Returns `(x, m)` where `x` is the smallest non-negative solution and
+/// every solution is of the form `x + k * m`, or [`None`] if the system has no solution.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Result<Option<(i64, i64)>, CrtError> {
+    let (mut x, mut m): (i128, i128) = (0, 1);
+
+    for (residue, modulus) in congruences {
+        if modulus <= 0 {
+            return Err(CrtError::NonPositiveModulus(modulus));
+        }
+        let (residue, modulus) = (i128::from(residue).rem_euclid(i128::from(modulus)), i128::from(modulus));
+
+        let (g, p, _) = extended_gcd_i128(m, modulus);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return Ok(None);
+        }
+
+        // x + m * t ≡ residue (mod modulus)  =>  t ≡ (diff / g) * p (mod modulus / g)
+        let step = modulus / g;
+        let t = ((diff / g) % step * (p % step)).rem_euclid(step);
+        x += m * t;
+        // NOTE: both factors fit into an i64 here, so the product cannot overflow an i128.
+        m *= step;
+        x = x.rem_euclid(m);
+
+        if m > i128::from(i64::MAX) {
+            return Err(CrtError::Overflow);
+        }
+    }
+
+    // NOTE: `0 <= x < m <= i64::MAX`, so neither conversion can fail.
+    #[allow(clippy::cast_possible_truncation)]
+    Ok(Some((x as i64, m as i64)))
+}
+
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    (old_r, old_x, old_y)
+}
+
+/// Integer square root, i.e. the largest `r` such that `r * r <= n`.
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method, starting from an estimate that is guaranteed to be too large.
+    let mut x = 1_u64 << ((64 - n.leading_zeros()).div_ceil(2));
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crt, extended_gcd, CrtError, gcd, gcd_all, isqrt, lcm, lcm_all, mod_inverse, mod_pow};
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([4, 6, 10]), Some(60));
+        assert_eq!(lcm_all(std::iter::empty()), Some(1));
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([1 << 40, 3 << 30, 7 << 20, 11 << 10, 13, 17, 19, 23]), None);
+    }
+
+    #[test]
+    fn extended_euclid_and_inverse() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_pow(4, 13, 497), 445);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok(Some((23, 105))));
+        // non-coprime moduli
+        assert_eq!(crt([(2, 4), (4, 6)]), Ok(Some((10, 12))));
+        assert_eq!(crt([(1, 4), (2, 6)]), Ok(None));
+        assert_eq!(crt([(0, 4), (1, 6)]), Ok(None));
+        assert_eq!(crt(std::iter::empty()), Ok(Some((0, 1))));
+        assert_eq!(crt([(1, 0)]), Err(CrtError::NonPositiveModulus(0)));
+        assert_eq!(crt([(0, i64::MAX), (0, 2)]), Err(CrtError::Overflow));
+        // a system without solution is reported before a later overflow
+        assert_eq!(crt([(1, 4), (2, 6), (0, i64::MAX)]), Ok(None));
+    }
+
+    #[test]
+    fn integer_sqrt() {
+        for n in [0, 1, 2, 3, 4, 15, 16, 17, 1 << 40, u64::MAX] {
+            let r = isqrt(n);
+            assert!(u128::from(r) * u128::from(r) <= u128::from(n));
+            assert!(u128::from(r + 1) * u128::from(r + 1) > u128::from(n));
+        }
+    }
+}
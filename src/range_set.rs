@@ -0,0 +1,287 @@
+//! Interval arithmetic for puzzles that map or combine large ranges of numbers.
+
+use std::cmp::{max, min};
+use std::fmt::Display;
+
+/// A half-open interval `start..end` of integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the interval of `len` values beginning at `start`.
+    pub const fn from_len(start: i64, len: i64) -> Self {
+        Self { start, end: start + len }
+    }
+
+    pub const fn len(&self) -> i64 {
+        if self.is_empty() {
+            return 0;
+        }
+        self.end - self.start
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub const fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// The values contained in both intervals. The result may be empty.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        Self::new(max(self.start, other.start), min(self.end, other.end))
+    }
+
+    /// Splits the interval into the values below `at` and the values from `at` onwards.
+    pub fn split_at(&self, at: i64) -> (Self, Self) {
+        let at = at.clamp(self.start, max(self.start, self.end));
+        (Self::new(self.start, at), Self::new(at, self.end))
+    }
+
+    /// The values of `self` that are not contained in `other`, as the parts below and above `other`.
+    pub fn subtract(&self, other: &Self) -> (Self, Self) {
+        if other.is_empty() {
+            return (*self, Self::new(self.end, self.end));
+        }
+        (Self::new(self.start, min(self.end, other.start)), Self::new(max(self.start, other.end), self.end))
+    }
+
+    /// Shifts the interval by `delta`.
+    #[must_use]
+    pub const fn offset(&self, delta: i64) -> Self {
+        Self::new(self.start + delta, self.end + delta)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of integers stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values contained in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    /// Adds all values of `interval` to the set, merging overlapping and adjacent intervals.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // intervals in `first..last` touch the new interval and are merged into it.
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let merged = self.intervals[first..last].iter().fold(interval, |acc, i| Interval::new(min(acc.start, i.start), max(acc.end, i.end)));
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Removes all values of `interval` from the set.
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|i| i.end <= interval.start);
+        let last = self.intervals.partition_point(|i| i.start < interval.end);
+
+        let remainders: Vec<_> = self.intervals[first..last]
+            .iter()
+            .flat_map(|i| {
+                let (below, above) = i.subtract(&interval);
+                [below, above]
+            })
+            .filter(|i| !i.is_empty())
+            .collect();
+        self.intervals.splice(first..last, remainders);
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for &interval in &other.intervals {
+            res.insert(interval);
+        }
+        res
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut res = Self::new();
+        let (mut a, mut b) = (0, 0);
+
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (self.intervals[a], other.intervals[b]);
+            let overlap = x.intersect(&y);
+            if !overlap.is_empty() {
+                res.intervals.push(overlap);
+            }
+            if x.end < y.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+
+        res
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for &interval in &other.intervals {
+            res.remove(interval);
+        }
+        res
+    }
+
+    /// Shifts every value of the set by `delta`.
+    #[must_use]
+    pub fn offset(&self, delta: i64) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.offset(delta)).collect(),
+        }
+    }
+
+    /// Maps the set through a list of `(source, delta)` rules: values inside a `source` interval are shifted by its
+    /// `delta`, values not covered by any rule are kept as they are. Source intervals are expected to be disjoint.
+    #[must_use]
+    pub fn map_offsets(&self, rules: impl IntoIterator<Item = (Interval, i64)>) -> Self {
+        let mut unmapped = self.clone();
+        let mut res = Self::new();
+
+        for (source, delta) in rules {
+            let covered = unmapped.intersection(&Self::from(source));
+            for &interval in &covered.intervals {
+                res.insert(interval.offset(delta));
+            }
+            unmapped = unmapped.difference(&covered);
+        }
+
+        res.union(&unmapped)
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Interval, RangeSet};
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals.iter().map(|&(s, e)| Interval::new(s, e)).collect()
+    }
+
+    #[test]
+    fn interval_operations() {
+        let i = Interval::new(2, 8);
+        assert_eq!(i.len(), 6);
+        assert_eq!(i.intersect(&Interval::new(5, 10)), Interval::new(5, 8));
+        assert!(i.intersect(&Interval::new(8, 10)).is_empty());
+        assert_eq!(i.split_at(4), (Interval::new(2, 4), Interval::new(4, 8)));
+        assert_eq!(i.subtract(&Interval::new(4, 6)), (Interval::new(2, 4), Interval::new(6, 8)));
+        assert_eq!(i.offset(-2), Interval::new(0, 6));
+    }
+
+    #[test]
+    fn insert_merges_adjacent_and_overlapping() {
+        let s = set(&[(10, 12), (0, 2), (2, 4), (6, 8), (3, 7)]);
+        assert_eq!(s, set(&[(0, 8), (10, 12)]));
+        assert!(s.contains(7));
+        assert!(!s.contains(8));
+        assert_eq!(s.min(), Some(0));
+        assert_eq!(s.max(), Some(11));
+        assert_eq!(s.intervals(), &[Interval::new(0, 8), Interval::new(10, 12)]);
+        assert_eq!(s.len(), 10);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+    }
+
+    #[test]
+    fn maps_offsets() {
+        // seed-to-soil map of the 2023 day 05 example.
+        let rules = [(Interval::from_len(98, 2), -48), (Interval::from_len(50, 48), 2)];
+        let seeds = set(&[(79, 93), (55, 68)]);
+        assert_eq!(seeds.map_offsets(rules), set(&[(57, 70), (81, 95)]));
+        assert_eq!(set(&[(96, 102)]).map_offsets(rules), set(&[(50, 52), (98, 100), (100, 102)]));
+    }
+}
@@ -0,0 +1,258 @@
+//! Generic graph searches (BFS, Dijkstra and A*) over arbitrary state types.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The outcome of a successful search.
+///
+/// The path is not materialized during the search, call [`SearchResult::path`] to reconstruct it.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    /// Total cost of the cheapest path from a start to the goal.
+    pub cost: C,
+    goal: usize,
+    states: Vec<S>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<S: Clone, C> SearchResult<S, C> {
+    /// The state that satisfied the goal predicate.
+    pub fn goal(&self) -> &S {
+        &self.states[self.goal]
+    }
+
+    /// Reconstructs the path from the start state to the goal, both inclusive.
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec![];
+        let mut current = Some(self.goal);
+
+        while let Some(index) = current {
+            path.push(self.states[index].clone());
+            current = self.parents[index];
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/// Book-keeping shared by all searches: interns states to indices so the heap does not need `S: Ord`.
+struct Visited<S, C> {
+    indices: HashMap<S, usize>,
+    states: Vec<S>,
+    costs: Vec<C>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<S: Hash + Eq + Clone, C: Copy + Ord> Visited<S, C> {
+    fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            states: vec![],
+            costs: vec![],
+            parents: vec![],
+        }
+    }
+
+    /// Records `state` if it is new or reachable at a lower cost than before. Returns its index in that case.
+    fn relax(&mut self, state: S, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if cost >= self.costs[index] {
+                    return None;
+                }
+                self.costs[index] = cost;
+                self.parents[index] = parent;
+                Some(index)
+            }
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push(entry.key().clone());
+                self.costs.push(cost);
+                self.parents.push(parent);
+                entry.insert(index);
+                Some(index)
+            }
+        }
+    }
+
+    fn into_result(self, goal: usize) -> SearchResult<S, C> {
+        SearchResult {
+            cost: self.costs[goal],
+            goal,
+            states: self.states,
+            parents: self.parents,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Breadth-first search where every transition costs one step.
+///
+/// Returns [`None`] if no state reachable from `starts` satisfies `is_goal`.
+pub fn bfs<S, I>(starts: impl IntoIterator<Item = S>, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<SearchResult<S, usize>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(index) = visited.relax(start, 0, None) {
+            queue.push_back(index);
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&visited.states[index]) {
+            return Some(visited.into_result(index));
+        }
+
+        let cost = visited.costs[index] + 1;
+        for next in successors(&visited.states[index]) {
+            if let Some(next_index) = visited.relax(next, cost, Some(index)) {
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's shortest path search. `successors` yields every neighbouring state together with the cost of moving there.
+///
+/// Returns [`None`] if no state reachable from `starts` satisfies `is_goal`.
+pub fn dijkstra<S, C, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<SearchResult<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal and must never overestimate it for the result to be optimal.
+///
+/// Returns [`None`] if no state reachable from `starts` satisfies `is_goal`.
+pub fn astar<S, C, I>(starts: impl IntoIterator<Item = S>, mut successors: impl FnMut(&S) -> I, mut heuristic: impl FnMut(&S) -> C, mut is_goal: impl FnMut(&S) -> bool) -> Option<SearchResult<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = visited.relax(start, C::default(), None) {
+            queue.push(QueueEntry { estimate, cost: C::default(), index });
+        }
+    }
+
+    while let Some(QueueEntry { cost, index, .. }) = queue.pop() {
+        // skip stale entries that were superseded by a cheaper path.
+        if cost > visited.costs[index] {
+            continue;
+        }
+
+        if is_goal(&visited.states[index]) {
+            return Some(visited.into_result(index));
+        }
+
+        for (next, step_cost) in successors(&visited.states[index]) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next_index) = visited.relax(next, next_cost, Some(index)) {
+                queue.push(QueueEntry {
+                    estimate,
+                    cost: next_cost,
+                    index: next_index,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Entry of the priority queue, ordered so that [`BinaryHeap`] pops the lowest estimate first.
+#[derive(PartialEq, Eq)]
+struct QueueEntry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for QueueEntry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate).then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for QueueEntry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::grid::{Coordinate, Grid};
+
+    fn maze() -> Grid<bool> {
+        Grid::parse("..#.\n.##.\n....", |c| c == '#').unwrap()
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let grid = maze();
+        let goal = Coordinate::new(0, 3);
+        let res = bfs([Coordinate::new(0, 0)], |&c| grid.neighbors(c).filter(|&n| !grid[n]).collect::<Vec<_>>(), |&c| c == goal).unwrap();
+
+        assert_eq!(res.cost, 7);
+        assert_eq!(res.path().len(), 8);
+        assert_eq!(res.path().first(), Some(&Coordinate::new(0, 0)));
+        assert_eq!(res.goal(), &goal);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_detour() {
+        // a -> b costs 10, a -> c -> b costs 3.
+        let edges = |s: &char| match s {
+            'a' => vec![('b', 10), ('c', 1)],
+            'c' => vec![('b', 2)],
+            _ => vec![],
+        };
+        let res = dijkstra(['a'], edges, |&s| s == 'b').unwrap();
+        assert_eq!(res.cost, 3);
+        assert_eq!(res.path(), vec!['a', 'c', 'b']);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = Grid::parse("1163\n1381\n2136", |c| c.to_digit(10).unwrap()).unwrap();
+        let goal = Coordinate::new(2, 3);
+        let successors = |&c: &Coordinate| grid.neighbors(c).map(|n| (n, grid[n])).collect::<Vec<_>>();
+
+        let a = astar([Coordinate::new(0, 0)], successors, |c| c.manhattan_distance(goal) as u32, |&c| c == goal).unwrap();
+        let d = dijkstra([Coordinate::new(0, 0)], successors, |&c| c == goal).unwrap();
+        assert_eq!(a.cost, d.cost);
+        assert_eq!(a.cost, 13);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let res = dijkstra([0_u32], |&s| if s < 5 { vec![(s + 1, 1_u32)] } else { vec![] }, |&s| s == 10);
+        assert!(res.is_none());
+    }
+}
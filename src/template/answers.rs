@@ -0,0 +1,159 @@
+//! Module that stores the accepted answer of each part in `data/<year>/answers/<day>.toml`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::PuzzleId;
+
+pub const ANSWERS_FOLDER: &str = "answers";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "invalid answers file: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// The known answers of one day. A part without an answer has not been solved yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.into()),
+            2 => self.part_two = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    /// Loads the answers at `path`. A missing file is treated as a day without answers.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = toml::to_string(self).map_err(|e| Error::Parser(e.to_string()))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+/// The outcome of checking a computed answer against the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Pass,
+    Fail { expected: String, actual: Option<String> },
+    Missing,
+}
+
+/// Compares the answer a part produced (or [`None`] if it failed) with the known answer.
+pub fn verify(expected: Option<&str>, actual: Option<&str>) -> Verification {
+    match (expected, actual) {
+        (None, _) => Verification::Missing,
+        (Some(expected), Some(actual)) if expected == actual => Verification::Pass,
+        (Some(expected), actual) => Verification::Fail {
+            expected: expected.into(),
+            actual: actual.map(Into::into),
+        },
+    }
+}
+
+#[must_use]
+pub fn get_path(id: PuzzleId) -> PathBuf {
+    id.data_path(ANSWERS_FOLDER, "toml")
+}
+
+pub fn load(id: PuzzleId) -> Result<Answers, Error> {
+    Answers::load(get_path(id))
+}
+
+/// Stores `answer` as the accepted answer of `part`.
+pub fn record(id: PuzzleId, part: u8, answer: &str) -> Result<(), Error> {
+    let path = get_path(id);
+    let mut answers = Answers::load(&path)?;
+    answers.set(part, answer);
+    answers.save(path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{verify, Answers, Verification};
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = Answers::parse("part_one = \"42\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.get(2), None);
+        assert!(Answers::parse("part_one = 42").is_err());
+    }
+
+    #[test]
+    fn serializes_set_parts_only() {
+        let mut answers = Answers::default();
+        answers.set(2, "1337");
+        assert_eq!(toml::to_string(&answers).unwrap(), "part_two = \"1337\"\n");
+    }
+
+    #[test]
+    fn verifies_answers() {
+        assert_eq!(verify(Some("1"), Some("1")), Verification::Pass);
+        assert_eq!(verify(None, Some("1")), Verification::Missing);
+        assert_eq!(
+            verify(Some("1"), Some("2")),
+            Verification::Fail {
+                expected: "1".into(),
+                actual: Some("2".into())
+            }
+        );
+        assert_eq!(
+            verify(Some("1"), None),
+            Verification::Fail {
+                expected: "1".into(),
+                actual: None
+            }
+        );
+    }
+}
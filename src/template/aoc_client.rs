@@ -0,0 +1,288 @@
+//! Minimal HTTP client for the Advent of Code website: downloads inputs and puzzles and submits answers.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::PuzzleId;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE: &str = ".adventofcode.session";
+const USER_AGENT: &str = concat!("github.com/baileysp/advent-of-code ", env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus { status: u16, url: String },
+    Transport(String),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set the AOC_SESSION environment variable or paste the cookie into ~/{SESSION_FILE}."
+            ),
+            AocClientError::BadStatus { status, url } => write!(f, "request to {url} failed with status {status}."),
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IoError(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::BadStatus {
+                status,
+                url: response.get_url().to_string(),
+            },
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Configures a client from the environment:
+    ///  - `AOC_SESSION` or the file `~/.adventofcode.session` for the session cookie.
+    ///  - `AOC_BASE_URL` to talk to a different server than adventofcode.com, e.g. a local mock.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = read_session()?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    fn day_url(&self, id: PuzzleId) -> String {
+        format!("{}/{}/day/{}", self.base_url, id.year, id.day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self.agent.get(url).set("Cookie", &format!("session={}", self.session)).call()?;
+        Ok(response.into_string()?)
+    }
+
+    pub fn get_input(&self, id: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(id)))
+    }
+
+    /// Fetches the puzzle description of all unlocked parts, converted to Markdown.
+    pub fn get_puzzle(&self, id: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(id))?;
+        Ok(articles_to_markdown(&html))
+    }
+
+    /// Submits `answer` for `part` and returns the response message, converted to Markdown.
+    pub fn submit(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(id)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", part.to_string().as_str()), ("answer", answer)])?;
+        Ok(articles_to_markdown(&response.into_string()?))
+    }
+}
+
+/// Converts the contents of every `<article>` of an Advent of Code page to Markdown.
+/// Puzzle pages contain one article per unlocked part, responses to submissions a single one.
+fn articles_to_markdown(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let article = &rest[start..start + end + "</article>".len()];
+        articles.push(html2md::parse_html(article).trim().to_string());
+        rest = &rest[start + end..];
+    }
+
+    articles.join("\n\n")
+}
+
+fn read_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session);
+        }
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")).ok_or(AocClientError::SessionNotFound)?;
+    match fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)) {
+        Ok(session) if !session.trim().is_empty() => Ok(session),
+        _ => Err(AocClientError::SessionNotFound),
+    }
+}
+
+fn get_input_path(id: PuzzleId) -> PathBuf {
+    id.data_path("inputs", "txt")
+}
+
+fn get_puzzle_path(id: PuzzleId) -> PathBuf {
+    id.data_path("puzzles", "md")
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Downloads the input and puzzle description of `id` to `data/<year>/inputs` and `data/<year>/puzzles`.
+pub fn download(id: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(id);
+    let puzzle_path = get_puzzle_path(id);
+
+    write_file(&input_path, &client.get_input(id)?)?;
+    write_file(&puzzle_path, &client.get_puzzle(id)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", input_path.display());
+    println!("🎄 Successfully wrote puzzle to \"{}\".", puzzle_path.display());
+    Ok(())
+}
+
+/// Fetches the puzzle description of `id`, saves it to `data/<year>/puzzles` and prints it.
+pub fn read(id: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let puzzle = client.get_puzzle(id)?;
+    write_file(&get_puzzle_path(id), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Submits `result` as the answer to `part` of `id` and returns the response message.
+pub fn submit(id: PuzzleId, part: u8, result: &str) -> Result<String, AocClientError> {
+    AocClient::from_env()?.submit(id, part, result)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles_to_markdown, AocClient, AocClientError};
+    use crate::{day, year, PuzzleId};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Serves a single request with `status` and `body`. Returns the server's url and a receiver for the raw request.
+    fn mock_server(status: u16, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body_buf = vec![0; content_length];
+            reader.read_exact(&mut body_buf).unwrap();
+            request.push_str(&String::from_utf8(body_buf).unwrap());
+
+            write!(stream, "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+            sender.send(request).unwrap();
+        });
+
+        (url, receiver)
+    }
+
+    #[test]
+    fn downloads_input_with_session() {
+        let (url, request) = mock_server(200, "1 2 3\n");
+        let client = AocClient::new(&url, "secret\n");
+
+        assert_eq!(client.get_input(PuzzleId::new(year!(2022), day!(5))).unwrap(), "1 2 3\n");
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/5/input HTTP/1.1"));
+        assert!(request.contains("session=secret\r\n"));
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let (url, _) = mock_server(200, "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>Hi <em>there</em></p></article><p>Your puzzle answer was <code>1</code>.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2></article></main>");
+        let client = AocClient::new(&url, "secret");
+
+        let puzzle = client.get_puzzle(PuzzleId::new(year!(2023), day!(1))).unwrap();
+        assert!(puzzle.contains("--- Day 1 ---"));
+        assert!(puzzle.contains("Hi *there*"));
+        assert!(puzzle.contains("--- Part Two ---"));
+        assert!(!puzzle.contains("Your puzzle answer"));
+    }
+
+    #[test]
+    fn submits_answer_as_form() {
+        let (url, request) = mock_server(200, "<main><article><p>That's the right answer!</p></article></main>");
+        let client = AocClient::new(&url, "secret");
+
+        assert_eq!(client.submit(PuzzleId::new(year!(2023), day!(12)), 2, "42").unwrap(), "That's the right answer!");
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/12/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, _) = mock_server(404, "not found");
+        let client = AocClient::new(&url, "secret");
+
+        match client.get_input(PuzzleId::new(year!(2023), day!(25))) {
+            Err(AocClientError::BadStatus { status, url }) => {
+                assert_eq!(status, 404);
+                assert!(url.ends_with("/2023/day/25/input"));
+            }
+            res => panic!("unexpected result: {res:?}"),
+        }
+    }
+
+    #[test]
+    fn ignores_content_outside_of_articles() {
+        assert_eq!(articles_to_markdown("<p>nothing</p>"), "");
+    }
+}
@@ -0,0 +1,274 @@
+//! Module that keeps every `cargo time` run in `data/benchmarks.json` and compares runs against each other.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+use crate::template::readme_benchmarks::Timings;
+use crate::template::runner::PARSE_STEP;
+use crate::template::stats::Stats;
+use crate::Year;
+
+pub const HISTORY_PATH: &str = "data/benchmarks.json";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "invalid benchmark history: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+/// The benchmark of one part in one run. Records sharing a timestamp belong to the same run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Short hash of the checked out commit, if the repository is a git repository.
+    pub commit: Option<String>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+/// The median of a part in the latest run compared to its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    /// Relative change of the median in percent, positive if the part got slower.
+    pub change_percent: f64,
+    pub is_regression: bool,
+}
+
+impl History {
+    /// Loads the history at `path`. A missing file is treated as an empty history.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Adds a run with the stats of every benched part in `timings`. `parse` steps are recorded as [`PARSE_STEP`].
+    pub fn push_run(&mut self, timings: &[Timings], commit: Option<String>, timestamp: u64) {
+        for timing in timings {
+            for (part, stats) in [(PARSE_STEP, timing.parse_stats), (1, timing.part_1_stats), (2, timing.part_2_stats)] {
+                if let Some(stats) = stats {
+                    self.records.push(Record {
+                        year: timing.year.into_inner(),
+                        day: timing.day.into_inner(),
+                        part,
+                        commit: commit.clone(),
+                        timestamp,
+                        stats,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Compares the latest run with the most recent earlier benchmark of each part.
+    ///
+    /// If `baseline_commit` is set, only runs of that commit are considered as a baseline. If `year` is set, only
+    /// records of that year are considered. Parts whose median got slower by more than `threshold_percent` are flagged
+    /// as regressions. Parts without a baseline are skipped.
+    pub fn compare(&self, threshold_percent: f64, baseline_commit: Option<&str>, year: Option<Year>) -> Vec<Comparison> {
+        let records: Vec<&Record> = self.records.iter().filter(|r| year.is_none_or(|y| r.year == y.into_inner())).collect();
+
+        let Some(latest) = records.iter().map(|r| r.timestamp).max() else {
+            return vec![];
+        };
+
+        let mut baselines: HashMap<(u16, u8, u8), &Record> = HashMap::new();
+        for &record in &records {
+            if record.timestamp >= latest || baseline_commit.is_some_and(|c| record.commit.as_deref() != Some(c)) {
+                continue;
+            }
+            let entry = baselines.entry((record.year, record.day, record.part)).or_insert(record);
+            if record.timestamp >= entry.timestamp {
+                *entry = record;
+            }
+        }
+
+        let mut comparisons: Vec<Comparison> = records
+            .iter()
+            .filter(|r| r.timestamp == latest)
+            .filter_map(|current| {
+                let baseline = baselines.get(&(current.year, current.day, current.part))?;
+                let (baseline_ns, current_ns) = (baseline.stats.median_ns, current.stats.median_ns);
+
+                #[allow(clippy::cast_precision_loss)]
+                let change_percent = if baseline_ns == 0 {
+                    0.0
+                } else {
+                    (current_ns as f64 - baseline_ns as f64) / baseline_ns as f64 * 100.0
+                };
+
+                Some(Comparison {
+                    year: current.year,
+                    day: current.day,
+                    part: current.part,
+                    baseline_ns,
+                    current_ns,
+                    change_percent,
+                    is_regression: change_percent > threshold_percent,
+                })
+            })
+            .collect();
+
+        comparisons.sort_by_key(|c| (c.year, c.day, c.part));
+        comparisons
+    }
+}
+
+/// Appends the run described by `timings` to the history file.
+pub fn record(timings: &[Timings]) -> Result<(), Error> {
+    let mut history = History::load(HISTORY_PATH)?;
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    history.push_run(timings, current_commit(), timestamp);
+    history.save(HISTORY_PATH)
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "--short", "HEAD"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::History;
+    use crate::{day, year};
+    use crate::template::readme_benchmarks::Timings;
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+
+    fn timings(part_1_ns: u64, part_2_ns: Option<u64>) -> Vec<Timings> {
+        let stats = |ns| Stats::from_samples(&[Duration::from_nanos(ns)]);
+        vec![Timings {
+            year: year!(2023),
+            day: day!(1),
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: stats(part_1_ns),
+            part_2_stats: part_2_ns.and_then(stats),
+            parse_memory: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            total_nanos: 0.0,
+        }]
+    }
+
+    #[test]
+    fn flags_regressions_against_previous_run() {
+        let mut history = History::default();
+        history.push_run(&timings(100, Some(100)), Some("aaa".into()), 1);
+        history.push_run(&timings(105, Some(150)), Some("bbb".into()), 2);
+
+        let res = history.compare(10.0, None, None);
+        assert_eq!(res.len(), 2);
+        assert!(!res[0].is_regression);
+        assert!(res[1].is_regression);
+        assert_eq!(res[1].change_percent, 50.0);
+    }
+
+    #[test]
+    fn compares_against_baseline_commit() {
+        let mut history = History::default();
+        history.push_run(&timings(100, None), Some("aaa".into()), 1);
+        history.push_run(&timings(200, None), Some("bbb".into()), 2);
+        history.push_run(&timings(190, None), Some("ccc".into()), 3);
+
+        assert!(!history.compare(10.0, None, None)[0].is_regression);
+        assert!(history.compare(10.0, Some("aaa"), None)[0].is_regression);
+        assert!(history.compare(10.0, Some("zzz"), None).is_empty());
+    }
+
+    #[test]
+    fn skips_parts_without_baseline() {
+        let mut history = History::default();
+        history.push_run(&timings(100, None), None, 1);
+        history.push_run(&timings(100, Some(100)), None, 2);
+
+        let res = history.compare(10.0, None, None);
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].part, 1);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut history = History::default();
+        history.push_run(&timings(100, Some(200)), Some("aaa".into()), 1);
+        let json = serde_json::to_string(&history).unwrap();
+        assert!(json.starts_with('['));
+        assert_eq!(serde_json::from_str::<History>(&json).unwrap(), history);
+    }
+
+    #[test]
+    fn compares_latest_run_of_year() {
+        let mut history = History::default();
+        history.push_run(&timings(100, None), None, 1);
+        history.push_run(&timings(200, None), None, 2);
+        let mut other_year = timings(100, None);
+        other_year[0].year = year!(2022);
+        history.push_run(&other_year, None, 3);
+
+        assert!(history.compare(10.0, None, None).is_empty());
+        let res = history.compare(10.0, None, Some(year!(2023)));
+        assert_eq!(res.len(), 1);
+        assert!(res[0].is_regression);
+    }
+}
@@ -1,31 +1,90 @@
 use std::io;
+use std::time::Duration;
 
 use crate::template::{
+    benchmark_history,
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    runner::BenchConfig,
+    solution::Registry,
+    try_read_file, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
-
-pub fn handle(is_release: bool, is_timed: bool) {
+use crate::{all_days, DaySelection, PuzzleId, Year};
+
+/// Runs every day of `years`, or only the selected `days` of each year. Days are labeled with their year if more than one year is run.
+/// With a `registry`, days run in-process instead of in their own binaries.
+/// With a `timeout`, a day is cancelled if one of its steps takes longer to compute its answer, and the next day is run.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    years: &[Year],
+    days: Option<&DaySelection>,
+    is_release: bool,
+    is_timed: bool,
+    bench_config: BenchConfig,
+    jobs: Option<usize>,
+    registry: Option<&Registry>,
+    timeout: Option<Duration>,
+) {
     let mut timings: Vec<Timings> = vec![];
+    let puzzles: Vec<PuzzleId> = years
+        .iter()
+        .flat_map(|&year| all_days(year).map(move |day| PuzzleId::new(year, day)))
+        .filter(|id| days.is_none_or(|days| days.contains(id.day)))
+        .collect();
+    let is_multi_year = years.len() > 1;
+
+    // timed runs stay serial so that solutions do not compete for the CPU while being benched.
+    let jobs = jobs.unwrap_or(1).max(1);
+    if is_timed && jobs > 1 {
+        eprintln!("{ANSI_ITALIC}Ignoring --jobs {jobs}: timed runs are always serial.{ANSI_RESET}");
+    } else if registry.is_some() && jobs > 1 {
+        eprintln!("{ANSI_ITALIC}Ignoring --jobs {jobs}: in-process runs are always serial.{ANSI_RESET}");
+    }
+
+    if registry.is_some() {
+        // the solutions are part of this binary already.
+    } else if let Err(e) = child_commands::build_solutions(is_release) {
+        eprintln!("Failed to build solutions: {e:?}");
+        std::process::exit(1);
+    }
 
-    all_days().for_each(|day| {
-        if day > 1 {
+    let mut is_first = true;
+    let mut print_day = |id: PuzzleId, output: Option<child_commands::SolutionOutput>| {
+        if !is_first {
             println!();
         }
+        is_first = false;
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+        if is_multi_year {
+            println!("{ANSI_BOLD}{} Day {}{ANSI_RESET}", id.year, id.day);
+            println!("-----------");
+        } else {
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", id.day);
+            println!("------");
+        }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let results = match (output, registry) {
+            (Some(output), _) => {
+                output.print();
+                output.results()
+            }
+            (None, Some(registry)) => run_in_process(registry, id),
+            (None, None) => child_commands::run_solution(id, is_timed, is_release, &bench_config, true, timeout).unwrap().results(),
+        };
 
-        if output.is_empty() {
+        if results.is_empty() {
             println!("Not solved.");
         } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+            timings.push(child_commands::parse_exec_time(&results, id));
         }
-    });
+    };
+
+    if is_timed || jobs == 1 || registry.is_some() {
+        puzzles.iter().for_each(|&id| print_day(id, None));
+    } else {
+        child_commands::run_solutions_parallel(&puzzles, is_release, jobs, timeout, |id, output| {
+            print_day(id, Some(output));
+        });
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
@@ -33,6 +92,11 @@ pub fn handle(is_release: bool, is_timed: bool) {
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
         if is_release {
+            match benchmark_history::record(&timings) {
+                Ok(()) => println!("Successfully recorded benchmarks in {}.", benchmark_history::HISTORY_PATH),
+                Err(e) => eprintln!("Failed to record benchmarks: {e}"),
+            }
+
             match readme_benchmarks::update(timings, total_millis) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
@@ -43,9 +107,25 @@ pub fn handle(is_release: bool, is_timed: bool) {
     }
 }
 
+/// Runs the solution of `id` from `registry` on its input. Days that are not registered or have no input have no results.
+fn run_in_process(registry: &Registry, id: PuzzleId) -> Vec<crate::template::runner::PartResult> {
+    let Some(solution) = registry.get(id) else {
+        return vec![];
+    };
+
+    match try_read_file("inputs", id) {
+        Ok(input) => (solution.run)(&input),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            vec![]
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    BuildFailed,
     Parser(String),
     IO(io::Error),
 }
@@ -56,48 +136,149 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+pub(crate) mod child_commands {
+    use super::Error;
+    use crate::template::memory;
+    use crate::template::runner::{BenchConfig, PartResult, Progress, ProgressEvent, PARSE_STEP, PROGRESS_FLAG};
+    use crate::PuzzleId;
     use std::{
+        collections::BTreeMap,
+        env,
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc::{self, RecvTimeoutError},
+        },
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// A line a solution printed to stdout.
+    pub enum OutputLine {
+        Result(PartResult),
+        Text(String),
+    }
+
+    /// Everything a solution printed, in order. `stderr` stays empty if it was echoed while running.
+    #[derive(Default)]
+    pub struct SolutionOutput {
+        pub lines: Vec<OutputLine>,
+        pub stderr: Vec<String>,
+    }
+
+    impl SolutionOutput {
+        pub fn results(&self) -> Vec<PartResult> {
+            self.lines
+                .iter()
+                .filter_map(|line| match line {
+                    OutputLine::Result(result) => Some(result.clone()),
+                    OutputLine::Text(_) => None,
+                })
+                .collect()
+        }
+
+        pub fn print(&self) {
+            for line in &self.stderr {
+                eprintln!("{line}");
+            }
+            for line in &self.lines {
+                match line {
+                    OutputLine::Result(result) => result.print(),
+                    OutputLine::Text(text) => println!("{text}"),
+                }
+            }
+        }
+    }
+
+    /// Features of this build that the solution bins need as well, i.e. the counting allocator of `memory`.
+    pub fn feature_args() -> Vec<&'static str> {
+        if memory::is_enabled() {
+            vec!["--features", "memory"]
+        } else {
+            vec![]
         }
+    }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// Builds all solution bins once, so they can be run directly (and concurrently) afterwards.
+    pub fn build_solutions(is_release: bool) -> Result<(), Error> {
+        build(&["--bins"], is_release)
+    }
 
+    /// Builds the solution bin of `id`, so it can be run directly afterwards.
+    pub fn build_solution(id: PuzzleId, is_release: bool) -> Result<(), Error> {
+        build(&["--bin", &id.to_string()], is_release)
+    }
+
+    fn build(targets: &[&str], is_release: bool) -> Result<(), Error> {
+        let mut args = vec!["build", "--quiet"];
+        args.extend(targets);
         if is_release {
             args.push("--release");
         }
+        args.extend(feature_args());
+
+        if Command::new("cargo").args(&args).status()?.success() {
+            Ok(())
+        } else {
+            Err(Error::BuildFailed)
+        }
+    }
+
+    fn get_path_for_executable(id: PuzzleId, is_release: bool) -> PathBuf {
+        let target_dir = env::var_os("CARGO_TARGET_DIR").map_or_else(|| PathBuf::from("target"), PathBuf::from);
+        let profile = if is_release { "release" } else { "debug" };
+        target_dir.join(profile).join(format!("{id}{}", env::consts::EXE_SUFFIX))
+    }
+
+    /// Run the solution bin for a given puzzle and collect everything it prints. Expects the bin to be built already.
+    /// With `echo`, output is forwarded as it arrives instead of being captured for later.
+    /// With a `timeout`, the bin is killed if a step takes longer to compute its answer, see [`run_executable`].
+    pub fn run_solution(
+        id: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        bench_config: &BenchConfig,
+        echo: bool,
+        timeout: Option<Duration>,
+    ) -> Result<SolutionOutput, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !id.bin_path().exists() {
+            return Ok(SolutionOutput::default());
+        }
+
+        let mut args = vec!["--format".to_string(), "json".to_string()];
 
         if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            // mirror `--time` and bench flags to child invocations.
+            args.push("--time".into());
+            args.extend(bench_config.to_args());
         }
 
+        run_executable(id, is_release, &args, echo, timeout)
+    }
+
+    /// Run the solution bin for a given puzzle on the input at `path` and capture everything it prints.
+    pub fn run_solution_on_input(id: PuzzleId, is_release: bool, path: &Path) -> Result<SolutionOutput, Error> {
+        let args = ["--format".to_string(), "json".to_string(), "--input".to_string(), path.display().to_string()];
+        run_executable(id, is_release, &args, false, None)
+    }
+
+    /// Runs the solution bin of `id` with `args`, which have to include `--format json`.
+    ///
+    /// With a `timeout`, the bin reports the [`Progress`] of its steps and is killed if a step does not compute its
+    /// answer within `timeout`. The step is then reported as timed out. Benching a step with `--time` is not timed.
+    pub fn run_executable(id: PuzzleId, is_release: bool, args: &[String], echo: bool, timeout: Option<Duration>) -> Result<SolutionOutput, Error> {
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward or capture stderr on a separate thread, and read stdout lines on another one so that waiting for
+        // them can time out.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut cmd = Command::new(get_path_for_executable(id, is_release))
+            .args(args)
+            .args(timeout.map(|_| PROGRESS_FLAG))
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -105,87 +286,204 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut output = SolutionOutput::default();
+
+        let stderr_thread = thread::spawn(move || {
+            let mut captured = vec![];
+            for line in stderr.lines().map_while(Result::ok) {
+                if echo {
+                    eprintln!("{line}");
+                } else {
+                    captured.push(line);
+                }
+            }
+            captured
+        });
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines() {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        // reading the input has to finish within `timeout` as well.
+        let mut deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut pending_step = None;
+        let mut timed_out = false;
+
+        loop {
+            let line = match deadline {
+                Some(deadline) => match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(line) => line,
+                    Err(RecvTimeoutError::Timeout) => {
+                        // the bin may have exited on its own in the meantime.
+                        let _ = cmd.kill();
+                        timed_out = true;
+                        break;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                },
+                None => match receiver.recv() {
+                    Ok(line) => line,
+                    Err(_) => break,
+                },
+            }?;
+
+            if let Some(progress) = parse_progress(&line) {
+                // only computing the answer of a step is timed, not benching it.
+                (pending_step, deadline) = match progress.event {
+                    ProgressEvent::Started => (Some(progress.part), timeout.map(|timeout| Instant::now() + timeout)),
+                    ProgressEvent::Answered => (None, None),
+                };
+                continue;
+            }
+
+            match parse_result(&line) {
+                Some(result) => {
+                    if echo {
+                        result.print();
+                    }
+                    output.lines.push(OutputLine::Result(result));
+                }
+                None => {
+                    if echo {
+                        println!("{line}");
+                    }
+                    output.lines.push(OutputLine::Text(line));
+                }
+            }
         }
 
-        thread.join().unwrap();
+        output.stderr = stderr_thread.join().unwrap();
+
+        match (pending_step, timeout.filter(|_| timed_out)) {
+            (None, Some(timeout)) => {
+                let message = format!("Timed out after {timeout:.1?} before starting a part.");
+                if echo {
+                    eprintln!("{message}");
+                } else {
+                    output.stderr.push(message);
+                }
+            }
+            (Some(part), Some(timeout)) => {
+                let result = PartResult::timeout(id, part, timeout);
+                if echo {
+                    result.print();
+                }
+                output.lines.push(OutputLine::Result(result));
+            }
+            _ => {}
+        }
         cmd.wait()?;
 
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
+    /// Runs the given puzzles on `jobs` worker threads and calls `on_output` for every puzzle, in the order of `puzzles`.
+    pub fn run_solutions_parallel(
+        puzzles: &[PuzzleId],
+        is_release: bool,
+        jobs: usize,
+        timeout: Option<Duration>,
+        mut on_output: impl FnMut(PuzzleId, SolutionOutput),
+    ) {
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs.min(puzzles.len()) {
+                let sender = sender.clone();
+                let next = &next;
+                scope.spawn(move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&id) = puzzles.get(index) else {
+                        break;
+                    };
+                    let output = run_solution(id, false, is_release, &BenchConfig::default(), false, timeout).unwrap_or_else(|e| SolutionOutput {
+                        lines: vec![],
+                        stderr: vec![format!("Failed to run {id}: {e:?}")],
+                    });
+                    if sender.send((index, output)).is_err() {
+                        break;
+                    }
+                });
+            }
+            drop(sender);
+
+            // buffer puzzles that finished early until all puzzles before them have been printed.
+            let mut pending = BTreeMap::new();
+            let mut printed = 0;
+            for (index, output) in receiver {
+                pending.insert(index, output);
+                while let Some(output) = pending.remove(&printed) {
+                    on_output(puzzles[printed], output);
+                    printed += 1;
+                }
+            }
+        });
+    }
+
+    /// Parses a line of [`Progress`] that a bin prints with [`PROGRESS_FLAG`].
+    fn parse_progress(line: &str) -> Option<Progress> {
+        if !line.starts_with('{') {
+            return None;
+        }
+        serde_json::from_str(line).ok()
+    }
+
+    /// Parses a line of `--format json` output. Returns [`None`] for lines the solution printed itself.
+    pub fn parse_result(line: &str) -> Option<PartResult> {
+        if !line.starts_with('{') {
+            return None;
+        }
+        serde_json::from_str(line).ok()
+    }
+
+    /// Shown instead of the time of a part that was cancelled, see [`PartResult::timed_out`].
+    pub const TIMEOUT: &str = "Timeout";
+
+    pub fn parse_exec_time(results: &[PartResult], id: PuzzleId) -> super::Timings {
         let mut timings = super::Timings {
-            day,
+            year: id.year,
+            day: id.day,
+            parse: None,
             part_1: None,
             part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_memory: None,
+            part_1_memory: None,
+            part_2_memory: None,
             total_nanos: 0_f64,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        // the parse step has no answer, only failed runs of it are skipped. Timed out parts are marked as such.
+        for result in results.iter().filter(|r| r.answer.is_some() || r.timed_out || (r.part == PARSE_STEP && r.error.is_none())) {
+            let timing_str = if result.timed_out { TIMEOUT.to_string() } else { format!("{:.1?}", result.duration()) };
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+            match result.part {
+                PARSE_STEP => (timings.parse, timings.parse_stats, timings.parse_memory) = (Some(timing_str), result.stats, result.memory),
+                1 => (timings.part_1, timings.part_1_stats, timings.part_1_memory) = (Some(timing_str), result.stats, result.memory),
+                2 => (timings.part_2, timings.part_2_stats, timings.part_2_memory) = (Some(timing_str), result.stats, result.memory),
+                _ => continue,
+            }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            if result.timed_out {
+                continue;
+            }
 
-                timings.total_nanos += nanos;
-            });
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = result.duration_ns as f64;
+            timings.total_nanos += nanos;
+        }
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -202,53 +500,99 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_progress, parse_result, TIMEOUT};
+        use std::time::Duration;
+
+        use crate::template::runner::{PartResult, ProgressEvent};
+        use crate::{day, year, PuzzleId};
 
-        use crate::day;
+        fn parse(lines: &[&str]) -> Vec<crate::template::runner::PartResult> {
+            lines.iter().filter_map(|l| parse_result(l)).collect()
+        }
 
         #[test]
         fn test_well_formed() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+                &parse(&[
+                    r#"{"year":2023,"day":1,"part":1,"answer":"0","error":null,"duration_ns":74,"samples":100000}"#,
+                    r#"{"year":2023,"day":1,"part":2,"answer":"10","error":null,"duration_ns":74130000,"samples":99999}"#,
+                ]),
+                PuzzleId::new(year!(2023), day!(1)),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
         fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
+            let results = parse(&[
+                "Part 1: @ @ @ ( ) ms (2s @ 5 samples)",
+                r#"{"year":2023,"day":1,"part":1,"answer":"(1 @ 2 samples)\n{}","error":null,"duration_ns":2000000000,"samples":5}"#,
+                "{ not json",
+                r#"{"year":2023,"day":1,"part":2,"answer":"10s","error":null,"duration_ns":100000000,"samples":1}"#,
+            ]);
+            assert_eq!(results.len(), 2);
+            assert_eq!(results[0].answer.as_deref(), Some("(1 @ 2 samples)\n{}"));
+
+            let res = parse_exec_time(&results, PuzzleId::new(year!(2023), day!(1)));
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
         fn test_missing_parts() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+                &parse(&[
+                    r#"{"year":2023,"day":1,"part":1,"answer":null,"error":"not implemented","duration_ns":10,"samples":1}"#,
+                    r#"{"year":2023,"day":1,"part":2,"answer":null,"error":"not implemented","duration_ns":10,"samples":1}"#,
+                ]),
+                PuzzleId::new(year!(2023), day!(1)),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn test_parse_step() {
+            let res = parse_exec_time(
+                &parse(&[
+                    r#"{"year":2023,"day":1,"part":0,"answer":null,"error":null,"duration_ns":1000,"samples":1}"#,
+                    r#"{"year":2023,"day":1,"part":1,"answer":"1","error":null,"duration_ns":10,"samples":1}"#,
+                    r#"{"year":2023,"day":1,"part":2,"answer":"2","error":null,"duration_ns":10,"samples":1}"#,
+                ]),
+                PuzzleId::new(year!(2023), day!(1)),
+            );
+            assert_approx_eq!(res.total_nanos, 1020_f64);
+            assert_eq!(res.parse.unwrap(), "1.0µs");
+            assert_eq!(res.part_1.unwrap(), "10.0ns");
+        }
+
+        #[test]
+        fn test_progress_lines() {
+            let started = r#"{"year":2023,"day":1,"part":0,"event":"started"}"#;
+            let progress = parse_progress(started).unwrap();
+            assert_eq!((progress.part, progress.event), (0, ProgressEvent::Started));
+            assert_eq!(parse_progress(r#"{"year":2023,"day":1,"part":1,"event":"answered"}"#).unwrap().event, ProgressEvent::Answered);
+            assert!(parse_result(started).is_none());
+
+            let result = r#"{"year":2023,"day":1,"part":1,"answer":"1","error":null,"duration_ns":10,"samples":1}"#;
+            assert!(parse_progress(result).is_none());
+            assert!(parse_progress("{ not json").is_none());
+        }
+
+        #[test]
+        fn test_timed_out_part() {
+            let id = PuzzleId::new(year!(2023), day!(1));
+            let mut results = parse(&[r#"{"year":2023,"day":1,"part":1,"answer":"1","error":null,"duration_ns":10,"samples":1}"#]);
+            results.push(PartResult::timeout(id, 2, Duration::from_secs(5)));
+
+            let res = parse_exec_time(&results, id);
+            assert_approx_eq!(res.total_nanos, 10_f64);
+            assert_eq!(res.part_1.unwrap(), "10.0ns");
+            assert_eq!(res.part_2.unwrap(), TIMEOUT);
+        }
     }
 }
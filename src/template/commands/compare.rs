@@ -0,0 +1,53 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::benchmark_history::{History, HISTORY_PATH};
+use crate::template::runner::PARSE_STEP;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::Year;
+
+/// Regressions are medians that got slower by more than this many percent, unless overridden with `--threshold`.
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+pub fn handle(threshold_percent: Option<f64>, baseline_commit: Option<&str>, year: Option<Year>) {
+    let threshold_percent = threshold_percent.unwrap_or(DEFAULT_THRESHOLD_PERCENT);
+
+    let history = match History::load(HISTORY_PATH) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to load benchmark history from {HISTORY_PATH}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let comparisons = history.compare(threshold_percent, baseline_commit, year);
+
+    if comparisons.is_empty() {
+        println!("Nothing to compare. Run `cargo time` at least twice to build up a benchmark history.");
+        return;
+    }
+
+    println!("{ANSI_BOLD}Year | Day | Part | Baseline | Current | Change{ANSI_RESET}");
+
+    for c in &comparisons {
+        let marker = if c.is_regression { "  ✖ regression" } else { "" };
+        println!(
+            "{} | {:02}  | {:<4} | {:.1?} | {:.1?} | {:+.1}%{marker}",
+            c.year,
+            c.day,
+            if c.part == PARSE_STEP { "parse".to_string() } else { c.part.to_string() },
+            Duration::from_nanos(c.baseline_ns),
+            Duration::from_nanos(c.current_ns),
+            c.change_percent
+        );
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.is_regression).count();
+
+    if regressions > 0 {
+        eprintln!("\n{regressions} part(s) got more than {threshold_percent}% slower.");
+        process::exit(1);
+    }
+
+    println!("\n{ANSI_ITALIC}No regressions above {threshold_percent}%.{ANSI_RESET}");
+}
@@ -1,15 +1,13 @@
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client;
+use crate::template::commands::scaffold;
+use crate::PuzzleId;
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(id: PuzzleId) {
+    if let Err(e) = aoc_client::download(id) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
     };
+
+    scaffold::prefill(id);
 }
@@ -0,0 +1,100 @@
+use std::process;
+
+use super::all::child_commands;
+use crate::template::answers::{self, Verification};
+use crate::template::examples;
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
+
+/// Runs the solution of `id` on every example of its manifest and prints a pass/fail table.
+pub fn handle(id: PuzzleId, is_release: bool) {
+    let manifest_path = examples::get_path(id);
+
+    let manifest = match examples::load(id) {
+        Ok(manifest) => manifest,
+        Err(examples::Error::IO(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            eprintln!("No example manifest found. Create \"{}\" to list the examples of this day.", manifest_path.display());
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to load \"{}\": {e}", manifest_path.display());
+            process::exit(1);
+        }
+    };
+
+    if manifest.examples.is_empty() {
+        println!("\"{}\" does not list any examples.", manifest_path.display());
+        return;
+    }
+
+    if !id.bin_path().exists() {
+        eprintln!("Day {} of {} has not been scaffolded yet.", id.day, id.year);
+        process::exit(1);
+    }
+
+    if let Err(e) = child_commands::build_solution(id, is_release) {
+        eprintln!("Failed to build {id}: {e:?}");
+        process::exit(1);
+    }
+
+    let mut rows = vec![["Example".to_string(), "Part 1".to_string(), "Part 2".to_string()]];
+    let mut failed = 0;
+
+    for example in &manifest.examples {
+        let path = examples::get_example_path(id, example);
+
+        let results = match child_commands::run_solution_on_input(id, is_release, &path) {
+            Ok(output) => {
+                // surface panics and read errors, they explain why a part produced no answer.
+                output.stderr.iter().for_each(|line| eprintln!("{line}"));
+                output.results()
+            }
+            Err(e) => {
+                eprintln!("Failed to run {id}: {e:?}");
+                process::exit(1);
+            }
+        };
+
+        let [part_1, part_2] = [1, 2].map(|part| {
+            let actual = results.iter().find(|r| r.part == part).and_then(|r| r.answer.as_deref());
+
+            match answers::verify(example.expected.get(part), actual) {
+                Verification::Pass => format!("✔ {}", actual.unwrap_or_default()),
+                Verification::Missing => "-".into(),
+                Verification::Fail { expected, actual } => {
+                    failed += 1;
+                    format!("✖ expected {expected}, got {}", actual.as_deref().unwrap_or("no answer"))
+                }
+            }
+        });
+
+        rows.push([example.file.clone(), part_1, part_2]);
+    }
+
+    print_table(&rows);
+
+    if failed > 0 {
+        eprintln!("\n{failed} part(s) do not match the expected answer.");
+        process::exit(1);
+    }
+}
+
+/// Prints `rows` as aligned columns, the first row as a bold header.
+fn print_table(rows: &[[String; 3]]) {
+    let widths: Vec<usize> = (0..3).map(|col| rows.iter().map(|row| row[col].chars().count()).max().unwrap_or(0)).collect();
+
+    for (index, row) in rows.iter().enumerate() {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell}{}", " ".repeat(width - cell.chars().count())))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        if index == 0 {
+            println!("{ANSI_BOLD}{}{ANSI_RESET}", line.trim_end());
+        } else {
+            println!("{}", line.trim_end());
+        }
+    }
+}
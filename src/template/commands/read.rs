@@ -1,16 +1,14 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client;
+use crate::template::commands::scaffold;
+use crate::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(id: PuzzleId) {
+    if let Err(e) = aoc_client::read(id) {
+        eprintln!("failed to read puzzle: {e}");
         process::exit(1);
     };
+
+    scaffold::prefill(id);
 }
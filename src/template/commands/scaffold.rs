@@ -1,20 +1,32 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::Day;
+use crate::template::{aoc_client, examples};
+use crate::PuzzleId;
+
+/// Replaces the built-in module template if present.
+const CUSTOM_TEMPLATE_PATH: &str = ".aoc/template.rs";
+/// Folder of the templates that can be selected with `--template <name>`.
+const NAMED_TEMPLATES_DIR: &str = ".aoc/templates";
+/// Selects the built-in module template, even if a custom one is present.
+const BUILTIN_TEMPLATE_NAME: &str = "builtin";
+const DEFAULT_OUTPUT_TYPE: &str = "u32";
+/// Output types whose answers are compared as integer literals in the tests of a module.
+const INTEGER_TYPES: [&str; 12] = ["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
 
 const MODULE_TEMPLATE: &str = r#"use anyhow::{Context, Result};
-advent_of_code::solution!(DAY_NUMBER);
+advent_of_code::solution!({{year}}, {{day}});
 
-pub fn part_one(input: &str) -> Result<u32> {
+pub fn part_one(input: &str) -> Result<{{part_one_type}}> {
     todo!()
 }
 
-pub fn part_two(input: &str) -> Result<u32> {
-    Ok(0)
+pub fn part_two(input: &str) -> Result<{{part_two_type}}> {
+    todo!()
 }
 
 #[cfg(test)]
@@ -24,74 +36,571 @@ mod tests {
     #[test]
     fn test_part_one() -> Result<()> {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY))?;
-        assert_eq!(result, 0);
+        assert_eq!(result, {{part_one_expected}});
         Ok(())
     }
 
     #[test]
     fn test_part_two() -> Result<()> {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY))?;
-        assert_eq!(result, 0);
+        let result = part_two(&{{part_two_example}})?;
+        assert_eq!(result, {{part_two_expected}});
         Ok(())
     }
 }
 "#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Writes `contents` to a new file at `path`. Returns `false` without touching the file if it already exists, unless
+/// `force` is set.
+fn write_new_file(path: &Path, contents: &str, force: bool) -> Result<bool, std::io::Error> {
+    create_parent_dir(path)?;
+    let mut options = OpenOptions::new();
+    if force {
+        options.write(true).create(true).truncate(true);
+    } else {
+        options.write(true).create_new(true);
+    }
+
+    match options.open(path) {
+        Ok(mut file) => file.write_all(contents.as_bytes()).map(|()| true),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+fn create_parent_dir(path: &Path) -> Result<(), std::io::Error> {
+    match path.parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Solutions live in per-year folders of `src/bin`, which cargo does not discover on its own.
+/// Adds a `[[bin]]` target named after the puzzle to `Cargo.toml`, unless it already has one.
+fn register_bin(id: PuzzleId) -> Result<bool, std::io::Error> {
+    let manifest = fs::read_to_string("Cargo.toml")?;
+    if manifest.contains(&format!("name = \"{id}\"")) {
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new().append(true).open("Cargo.toml")?;
+    let separator = if manifest.ends_with('\n') { "" } else { "\n" };
+    write!(file, "{separator}\n[[bin]]\nname = \"{id}\"\npath = \"{}\"\n", id.bin_path().display())?;
+    Ok(true)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Values of the `{{placeholder}}`s in module templates.
+pub struct TemplateValues {
+    pub id: PuzzleId,
+    /// Title of the puzzle, e.g. `Trebuchet?!`, if its description was downloaded.
+    pub title: Option<String>,
+    pub part_one_type: String,
+    pub part_two_type: String,
+    /// Expected answers of the first example in the manifest that has one for the part.
+    pub part_one_expected: Option<String>,
+    pub part_two_expected: Option<String>,
+    /// Whether part two has an example of its own in `<day>-2.txt`.
+    pub part_two_has_own_example: bool,
+}
+
+impl TemplateValues {
+    fn placeholders(&self) -> [(&'static str, String); 9] {
+        [
+            ("year", self.id.year.to_string()),
+            ("day", self.id.day.into_inner().to_string()),
+            ("day_padded", self.id.day.to_string()),
+            ("title", self.title.clone().unwrap_or_else(|| format!("Day {}", self.id.day.into_inner()))),
+            ("part_one_type", self.part_one_type.clone()),
+            ("part_two_type", self.part_two_type.clone()),
+            ("part_one_expected", expected_literal(self.part_one_expected.as_deref(), Some(&self.part_one_type)).0),
+            ("part_two_expected", expected_literal(self.part_two_expected.as_deref(), Some(&self.part_two_type)).0),
+            ("part_two_example", example_reader(self.part_two_has_own_example)),
+        ]
+    }
+}
+
+/// Replaces every known `{{placeholder}}` in `template`. Unknown placeholders are left as they are.
+///
+/// Expected answers that are compared as strings, see [`expected_literal`], turn `assert_eq!(result, <answer>);` into
+/// `assert_eq!(result.to_string(), <answer>);`.
+pub fn render(template: &str, values: &TemplateValues) -> String {
+    let module = values
+        .placeholders()
+        .iter()
+        .fold(template.to_string(), |module, (name, value)| module.replace(&format!("{{{{{name}}}}}"), value));
+
+    [
+        expected_literal(values.part_one_expected.as_deref(), Some(&values.part_one_type)),
+        expected_literal(values.part_two_expected.as_deref(), Some(&values.part_two_type)),
+    ]
+    .into_iter()
+    .filter(|(_, is_string)| *is_string)
+    .fold(module, |module, (literal, _)| module.replace(&format!("assert_eq!(result, {literal});"), &expected_assertion(&literal, true)))
+}
+
+/// How an expected answer is written in the tests of a module, and whether it is compared as a string. Integers are
+/// written as they are if the part returns an integer type, any other answer, e.g. `CMZ`, as a string literal that the
+/// result is compared with via `to_string()`, so that the module compiles. Unknown answers are written as the default
+/// value of the output type, see [`unknown_literal`].
+fn expected_literal(expected: Option<&str>, output_type: Option<&str>) -> (String, bool) {
+    let Some(expected) = expected else {
+        return (unknown_literal(output_type), false);
+    };
+
+    let digits = expected.strip_prefix('-').unwrap_or(expected);
+    let is_integer = !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+
+    if is_integer && output_type.is_some_and(|t| INTEGER_TYPES.contains(&t.trim())) {
+        (expected.into(), false)
+    } else {
+        (format!("{expected:?}"), true)
+    }
+}
+
+/// Placeholder for an unknown answer that compiles for any output type: `0` for integer types and types that are not
+/// known, otherwise the default value of the type, e.g. `String::default()` or `<Box<str>>::default()`.
+fn unknown_literal(output_type: Option<&str>) -> String {
+    match output_type.map(str::trim) {
+        None => "0".into(),
+        Some(t) if INTEGER_TYPES.contains(&t) => "0".into(),
+        Some(t) if t.chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':') => format!("{t}::default()"),
+        Some(t) => format!("<{t}>::default()"),
+    }
+}
+
+fn expected_assertion(literal: &str, is_string: bool) -> String {
+    if is_string {
+        format!("assert_eq!(result.to_string(), {literal});")
+    } else {
+        format!("assert_eq!(result, {literal});")
+    }
+}
+
+/// Reads the output type of a part from its signature in a module, e.g. `u64` of `fn part_one(input: &str) -> Result<u64> {`.
+fn read_output_type<'a>(module: &'a str, part_fn: &str) -> Option<&'a str> {
+    let signature = module.lines().find(|line| line.contains(&format!("fn {part_fn}(")))?;
+    let (_, output) = signature.split_once("-> Result<")?;
+    output.trim_end().trim_end_matches('{').trim_end().strip_suffix('>')
+}
+
+/// Reads the module template to scaffold with: the one selected with `--template <name>`, the custom template in `.aoc`
+/// or the built-in one, in that order.
+fn load_template(name: Option<&str>) -> Result<String, String> {
+    let path = match name {
+        Some(BUILTIN_TEMPLATE_NAME) => return Ok(MODULE_TEMPLATE.into()),
+        Some(name) => Path::new(NAMED_TEMPLATES_DIR).join(format!("{name}.rs")),
+        None if Path::new(CUSTOM_TEMPLATE_PATH).exists() => PathBuf::from(CUSTOM_TEMPLATE_PATH),
+        None => return Ok(MODULE_TEMPLATE.into()),
+    };
+
+    fs::read_to_string(&path).map_err(|e| format!("could not read template \"{}\": {e}", path.display()))
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Reads the title from the heading of a downloaded puzzle description, e.g. `--- Day 1: Trebuchet?! ---`.
+fn read_title(id: PuzzleId) -> Option<String> {
+    let puzzle = fs::read_to_string(id.data_path("puzzles", "md")).ok()?;
+    let heading = puzzle.lines().find(|line| line.contains("--- Day "))?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim_end_matches(['-', ' ', '#']).trim().to_string())
+}
+
+/// The expression that reads the example of part two in the tests of a module.
+fn example_reader(is_own_example: bool) -> String {
+    if is_own_example {
+        r#"advent_of_code::template::read_file_part("examples", DAY, 2)"#.into()
+    } else {
+        r#"advent_of_code::template::read_file("examples", DAY)"#.into()
+    }
+}
+
+fn part_two_example_file(id: PuzzleId) -> String {
+    format!("{}-2.txt", id.day)
+}
+
+/// Reads the expected answers of both parts from the example manifest, and whether part two has its own example.
+fn read_expected_answers(id: PuzzleId) -> (Option<String>, Option<String>, bool) {
+    let Ok(manifest) = examples::load(id) else {
+        return (None, None, false);
+    };
+    let expected = |part: u8| manifest.example_for(part).and_then(|e| e.expected.get(part).map(String::from));
+    let has_own_example = manifest.example_for(2).is_some_and(|e| e.file == part_two_example_file(id));
+    (expected(1), expected(2), has_own_example)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Writes the examples of a downloaded puzzle description to `data/<year>/examples` and adds their answers to the
+/// example manifest. Example files that are not empty and answers that are already in the manifest are kept.
+///
+/// Part two gets an example file of its own (`<day>-2.txt`) if its example differs from the one of part one.
+pub fn prefill_examples(id: PuzzleId) -> Result<(), String> {
+    let Ok(puzzle) = fs::read_to_string(id.data_path("puzzles", "md")) else {
+        return Ok(());
+    };
+    let parts = examples::parse_puzzle(&puzzle);
+
+    let manifest_path = examples::get_path(id);
+    let mut manifest = match examples::load(id) {
+        Ok(manifest) => manifest,
+        Err(examples::Error::IO(e)) if e.kind() == std::io::ErrorKind::NotFound => examples::Manifest::default(),
+        Err(e) => return Err(format!("could not read \"{}\": {e}", manifest_path.display())),
+    };
+    let mut is_manifest_changed = false;
+
+    for (part, example) in (1..).zip(&parts) {
+        let is_own_example = part == 2 && example.input.is_some() && example.input != parts[0].input;
+        let file = if is_own_example { part_two_example_file(id) } else { format!("{}.txt", id.day) };
+
+        if let Some(input) = &example.input {
+            let path = manifest_path.with_file_name(&file);
+            if fs::metadata(&path).map_or(true, |m| m.len() == 0) {
+                create_parent_dir(&path)
+                    .and_then(|()| fs::write(&path, input))
+                    .map_err(|e| format!("could not write \"{}\": {e}", path.display()))?;
+                println!("Wrote example of part {part} to \"{}\"", path.display());
+            }
+        }
+
+        if let Some(answer) = &example.answer {
+            let example = manifest.example_mut(&file);
+            if example.expected.get(part).is_none() {
+                example.expected.set(part, answer);
+                is_manifest_changed = true;
+            }
+        }
+    }
+
+    if is_manifest_changed {
+        manifest.save(&manifest_path).map_err(|e| format!("could not write \"{}\": {e}", manifest_path.display()))?;
+        println!("Added example answers to \"{}\"", manifest_path.display());
+    }
+
+    Ok(())
+}
+
+/// Fills the expected answers of the example manifest into the tests of a module that was scaffolded from the
+/// built-in template before they were known, i.e. replaces the assertion of an unknown answer, e.g.
+/// `assert_eq!(result, 0);`, in `test_part_one` and `test_part_two`.
+pub fn fill_module_expectations(id: PuzzleId) -> Result<(), String> {
+    let module_path = id.bin_path();
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        return Ok(());
+    };
+
+    let (part_one_expected, part_two_expected, part_two_has_own_example) = read_expected_answers(id);
+    let filled = fill_expectations(&module, part_one_expected, part_two_expected, part_two_has_own_example);
+
+    if filled != module {
+        fs::write(&module_path, filled).map_err(|e| format!("could not write \"{}\": {e}", module_path.display()))?;
+        println!("Filled in expected example answers in \"{}\"", module_path.display());
+    }
+
+    Ok(())
+}
+
+fn fill_expectations(module: &str, part_one_expected: Option<String>, part_two_expected: Option<String>, part_two_has_own_example: bool) -> String {
+    let mut filled = module.to_string();
+
+    for (test_name, part_fn, expected) in [("test_part_one", "part_one", part_one_expected), ("test_part_two", "part_two", part_two_expected)] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let Some(start) = filled.find(&format!("fn {test_name}(")) else {
+            continue;
+        };
+        let end = filled[start + 1..].find("fn ").map_or(filled.len(), |i| start + 1 + i);
+
+        let output_type = read_output_type(&filled, part_fn);
+        let unknown = expected_assertion(&unknown_literal(output_type), false);
+        let (literal, is_string) = expected_literal(Some(&expected), output_type);
+        let mut test = filled[start..end].replacen(&unknown, &expected_assertion(&literal, is_string), 1);
+        if test_name == "test_part_two" && part_two_has_own_example {
+            test = test.replacen(&example_reader(false), &example_reader(true), 1);
+        }
+        filled.replace_range(start..end, &test);
+    }
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    filled
+}
+
+/// Pre-fills examples and expected answers from a downloaded puzzle description.
+pub fn prefill(id: PuzzleId) {
+    if let Err(e) = prefill_examples(id).and_then(|()| fill_module_expectations(id)) {
+        eprintln!("Failed to pre-fill examples: {e}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// What scaffolding did to the example manifest of a day.
+enum ManifestChange {
+    Created,
+    Added,
+    Unchanged,
+}
+
+/// Creates the example manifest of a day, or adds the day's example file to an existing manifest. Examples that are
+/// already listed keep their expected answers.
+fn add_manifest_example(id: PuzzleId) -> Result<ManifestChange, examples::Error> {
+    let path = examples::get_path(id);
+    let file = format!("{}.txt", id.day);
+
+    if !path.exists() {
+        write_new_file(&path, &format!("[[example]]\nfile = \"{file}\"\n# part_one = \"\"\n# part_two = \"\"\n"), false)?;
+        return Ok(ManifestChange::Created);
+    }
+
+    let mut manifest = examples::Manifest::load(&path)?;
+    if manifest.examples.iter().any(|e| e.file == file) {
+        return Ok(ManifestChange::Unchanged);
+    }
+
+    manifest.example_mut(&file);
+    manifest.save(&path)?;
+    Ok(ManifestChange::Added)
+}
+
+/// Creates the module, input, example and example manifest of a day. Files that already exist are skipped and
+/// reported. `force` replaces an existing module, but never an input, example or the answers in the manifest. With `download`, the input and puzzle description are downloaded first, so that
+/// examples and expected answers can be pre-filled right away.
+pub fn handle(id: PuzzleId, template: Option<&str>, output_type: Option<&str>, force: bool, download: bool) {
+    let input_path = id.data_path("inputs", "txt");
+    let example_path = id.data_path("examples", "txt");
+    let manifest_path = examples::get_path(id);
+    let module_path = id.bin_path();
+
+    let template = match load_template(template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to load module template: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+    let is_downloaded = download
+        && match aoc_client::download(id) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("Failed to download puzzle, scaffolding without it: {e}");
+                false
+            }
+        };
+
+    let mut skipped = vec![];
+    let write = |skipped: &mut Vec<PathBuf>, path: &Path, contents: &str, description: &str, force: bool| match write_new_file(path, contents, force) {
+        Ok(true) => println!("Created {description} \"{}\"", path.display()),
+        Ok(false) => skipped.push(path.to_path_buf()),
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to create {description} \"{}\": {e}", path.display());
             process::exit(1);
         }
+    };
+
+    // inputs, examples and their answers can't be scaffolded again, so `force` only ever replaces the module.
+    if !is_downloaded {
+        write(&mut skipped, &input_path, "", "empty input file", false);
     }
+    write(&mut skipped, &example_path, "", "empty example file", false);
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
+    match add_manifest_example(id) {
+        Ok(ManifestChange::Created) => println!("Created example manifest \"{}\"", manifest_path.display()),
+        Ok(ManifestChange::Added) => println!("Added \"{}.txt\" to example manifest \"{}\"", id.day, manifest_path.display()),
+        Ok(ManifestChange::Unchanged) => skipped.push(manifest_path.clone()),
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to update example manifest \"{}\": {e}", manifest_path.display());
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
+    // fill in examples before rendering the module, so that it gets their answers.
+    if let Err(e) = prefill_examples(id) {
+        eprintln!("Failed to pre-fill examples: {e}");
+    }
+
+    let (part_one_expected, part_two_expected, part_two_has_own_example) = read_expected_answers(id);
+    let output_type = output_type.unwrap_or(DEFAULT_OUTPUT_TYPE);
+    let values = TemplateValues {
+        id,
+        title: read_title(id),
+        part_one_type: output_type.into(),
+        part_two_type: output_type.into(),
+        part_one_expected,
+        part_two_expected,
+        part_two_has_own_example,
+    };
+    write(&mut skipped, &module_path, &render(&template, &values), "module file", force);
+
+    match register_bin(id) {
+        Ok(true) => println!("Added bin \"{id}\" to \"Cargo.toml\""),
+        Ok(false) => {}
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("Failed to add bin to Cargo.toml: {e}");
             process::exit(1);
         }
     }
 
+    for path in &skipped {
+        println!("Skipped \"{}\", it already exists", path.display());
+    }
+    if skipped.contains(&module_path) {
+        println!("Pass `--force` to overwrite the module file.");
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!("🎄 Type `cargo solve {} --year {}` to run your solution.", id.day, id.year);
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{fs, process::Command};
+
+    use super::{fill_expectations, render, TemplateValues, MODULE_TEMPLATE};
+    use crate::{day, year, PuzzleId};
+
+    fn values() -> TemplateValues {
+        TemplateValues {
+            id: PuzzleId::new(year!(2023), day!(7)),
+            title: Some("Camel Cards".into()),
+            part_one_type: "u64".into(),
+            part_two_type: "String".into(),
+            part_one_expected: Some("6440".into()),
+            part_two_expected: None,
+            part_two_has_own_example: true,
+        }
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let module = render("// {{year}} day {{day}} ({{day_padded}}): {{title}} {{unknown}}", &values());
+        assert_eq!(module, "// 2023 day 7 (07): Camel Cards {{unknown}}");
+    }
+
+    #[test]
+    fn renders_builtin_template() {
+        let module = render(MODULE_TEMPLATE, &values());
+        assert!(module.contains("advent_of_code::solution!(2023, 7);"));
+        assert!(module.contains("pub fn part_one(input: &str) -> Result<u64>"));
+        assert!(module.contains("pub fn part_two(input: &str) -> Result<String>"));
+        assert!(module.contains("assert_eq!(result, 6440);"));
+        assert!(module.contains("assert_eq!(result, String::default());"));
+        assert!(!module.contains("Ok(0)"));
+        assert!(module.contains(r#"part_two(&advent_of_code::template::read_file_part("examples", DAY, 2))?"#));
+        assert!(!module.contains("{{"));
+    }
+
+    #[test]
+    fn fills_expectations_of_scaffolded_module() {
+        let module = render(
+            MODULE_TEMPLATE,
+            &TemplateValues {
+                part_one_expected: None,
+                part_two_has_own_example: false,
+                ..values()
+            },
+        );
+
+        let filled = fill_expectations(&module, Some("6440".into()), Some("5905".into()), true);
+        assert!(filled.contains("assert_eq!(result, 6440);"));
+        assert!(filled.contains(r#"assert_eq!(result.to_string(), "5905");"#));
+        assert!(filled.contains(r#"part_one(&advent_of_code::template::read_file("examples", DAY))?"#));
+        assert!(filled.contains(r#"part_two(&advent_of_code::template::read_file_part("examples", DAY, 2))?"#));
+
+        assert_eq!(fill_expectations(&filled, Some("1".into()), None, false), filled);
+    }
+
+    #[test]
+    fn compares_non_integer_answers_as_strings() {
+        let module = render(
+            MODULE_TEMPLATE,
+            &TemplateValues {
+                part_one_expected: Some("CMZ".into()),
+                part_two_expected: Some("42".into()),
+                ..values()
+            },
+        );
+        assert!(module.contains(r#"assert_eq!(result.to_string(), "CMZ");"#));
+        assert!(module.contains(r#"assert_eq!(result.to_string(), "42");"#));
+
+        let module = render(
+            MODULE_TEMPLATE,
+            &TemplateValues {
+                part_one_expected: None,
+                part_two_expected: None,
+                part_one_type: "u32".into(),
+                ..values()
+            },
+        );
+        let filled = fill_expectations(&module, Some("CMZ".into()), Some("-7".into()), false);
+        assert!(filled.contains(r#"assert_eq!(result.to_string(), "CMZ");"#));
+        assert!(filled.contains(r#"assert_eq!(result.to_string(), "-7");"#));
+    }
+
+    /// Stand-ins for the parts of `anyhow` and `advent_of_code` that the built-in template uses, so that a rendered
+    /// module can be type-checked with `rustc` alone.
+    const STAND_INS: &str = r#"
+mod anyhow {
+    pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+    pub trait Context {}
+}
+
+mod advent_of_code {
+    macro_rules! solution {
+        ($year:expr, $day:expr) => {
+            pub const DAY: u8 = $day;
+
+            pub fn solution() {
+                advent_of_code::part(part_one);
+                advent_of_code::part(part_two);
+            }
+        };
+    }
+    pub(crate) use solution;
+
+    pub fn part<T: std::fmt::Display>(_part: fn(&str) -> crate::anyhow::Result<T>) {}
+
+    pub mod template {
+        pub fn read_file(_folder: &str, _day: u8) -> String {
+            String::new()
+        }
+
+        pub fn read_file_part(_folder: &str, _day: u8, _part: u8) -> String {
+            String::new()
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn renders_modules_that_compile() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for (output_type, expected) in [("u64", None), ("u64", Some("6440")), ("String", None), ("String", Some("CMZ")), ("Box<str>", None)] {
+            let module = render(
+                MODULE_TEMPLATE,
+                &TemplateValues {
+                    part_one_type: output_type.into(),
+                    part_two_type: output_type.into(),
+                    part_one_expected: expected.map(String::from),
+                    part_two_expected: None,
+                    ..values()
+                },
+            );
+            let path = dir.join("module.rs");
+            fs::write(&path, format!("{STAND_INS}\n{module}")).unwrap();
+
+            let output = Command::new("rustc")
+                .args(["--edition", "2021", "--crate-type", "lib", "--test", "--emit", "metadata", "-A", "warnings", "--out-dir"])
+                .arg(&dir)
+                .arg(&path)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "`{output_type}` module does not compile:\n{}", String::from_utf8_lossy(&output.stderr));
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -1,23 +1,68 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
 
-use crate::Day;
+use super::all::child_commands::{self, OutputLine};
+use crate::template::runner::{BenchConfig, OutputFormat};
+use crate::template::solution::Registry;
+use crate::template::InputSource;
+use crate::PuzzleId;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
-
-    if release {
-        cmd_args.push("--release".to_string());
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    id: PuzzleId,
+    release: bool,
+    time: bool,
+    submit_part: Option<u8>,
+    force: bool,
+    format: Option<OutputFormat>,
+    bench_config: BenchConfig,
+    input: Option<String>,
+    registry: Option<&Registry>,
+    timeout: Option<Duration>,
+) {
+    if let Some(registry) = registry {
+        return run_in_process(registry, id, input.as_deref());
     }
 
-    cmd_args.push("--".to_string());
+    let mut bin_args = vec![];
 
     if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+        bin_args.push("--submit".to_string());
+        bin_args.push(submit_part.to_string());
+
+        if force {
+            bin_args.push("--force".to_string());
+        }
     }
 
     if time {
-        cmd_args.push("--time".to_string());
+        bin_args.push("--time".to_string());
+        bin_args.extend(bench_config.to_args());
+    }
+
+    if let Some(input) = input {
+        bin_args.push("--input".to_string());
+        bin_args.push(input);
+    }
+
+    if let Some(timeout) = timeout {
+        return run_with_timeout(id, release, bin_args, format, timeout);
+    }
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), id.to_string()];
+
+    if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    cmd_args.extend(child_commands::feature_args().into_iter().map(String::from));
+
+    cmd_args.push("--".to_string());
+    cmd_args.extend(bin_args);
+
+    if let Some(format) = format {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
     }
 
     let mut cmd = Command::new("cargo")
@@ -29,3 +74,49 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Builds the bin of `id` first, so that compiling does not count towards `timeout`, then runs it with `--format json`
+/// to tell when each step starts and has its answer. The results are printed in `format` as they come in.
+fn run_with_timeout(id: PuzzleId, release: bool, mut bin_args: Vec<String>, format: Option<OutputFormat>, timeout: Duration) {
+    if let Err(e) = child_commands::build_solution(id, release) {
+        eprintln!("Failed to build {id}: {e:?}");
+        process::exit(1);
+    }
+
+    bin_args.push("--format".to_string());
+    bin_args.push(OutputFormat::Json.to_string());
+
+    let is_json = format == Some(OutputFormat::Json);
+
+    let output = child_commands::run_executable(id, release, &bin_args, !is_json, Some(timeout)).unwrap_or_else(|e| {
+        eprintln!("Failed to run {id}: {e:?}");
+        process::exit(1);
+    });
+
+    if is_json {
+        for line in &output.stderr {
+            eprintln!("{line}");
+        }
+        for line in &output.lines {
+            match line {
+                OutputLine::Result(result) => result.print_json(),
+                OutputLine::Text(text) => println!("{text}"),
+            }
+        }
+    }
+}
+
+/// Runs the solution of `id` in this process. Flags like `--time`, `--submit` and `--format` are read by the runner.
+fn run_in_process(registry: &Registry, id: PuzzleId, input: Option<&str>) {
+    let Some(solution) = registry.get(id) else {
+        eprintln!("{id} is not part of this binary, it has no solution in \"{}\".", id.bin_path().display());
+        process::exit(1);
+    };
+
+    let input = InputSource::new(input).read(id).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    });
+
+    (solution.run)(&input);
+}
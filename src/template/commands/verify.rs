@@ -0,0 +1,81 @@
+use std::process;
+
+use super::all::child_commands;
+use crate::template::answers::{self, Verification};
+use crate::template::runner::BenchConfig;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::{all_days, Day, PuzzleId, Year};
+
+/// Runs the solutions of `day` (or every day with answers) and checks them against the answers in `data/<year>/answers`.
+pub fn handle(year: Year, day: Option<Day>, is_release: bool) {
+    let puzzles: Vec<PuzzleId> = match day {
+        Some(day) => vec![PuzzleId::new(year, day)],
+        None => all_days(year).map(|day| PuzzleId::new(year, day)).filter(|&id| answers::get_path(id).exists()).collect(),
+    };
+
+    if puzzles.is_empty() {
+        println!("No answers recorded yet. Submit a solution with `cargo solve <day> --submit <part>` to record one.");
+        return;
+    }
+
+    // a single day only needs its own bin, days that are not scaffolded are reported as such below.
+    let built = match puzzles.as_slice() {
+        [id] if !id.bin_path().exists() => Ok(()),
+        [id] => child_commands::build_solution(*id, is_release),
+        _ => child_commands::build_solutions(is_release),
+    };
+    if let Err(e) = built {
+        eprintln!("Failed to build solutions: {e:?}");
+        process::exit(1);
+    }
+
+    let mut failed = 0;
+
+    for id in puzzles {
+        let day = id.day;
+        let expected = match answers::load(id) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Failed to load answers for day {day}: {e}");
+                process::exit(1);
+            }
+        };
+
+        let results = match child_commands::run_solution(id, false, is_release, &BenchConfig::default(), false, None) {
+            Ok(output) => {
+                // surface panics and other diagnostics, they explain why a part produced no answer.
+                output.stderr.iter().for_each(|line| eprintln!("{line}"));
+                output.results()
+            }
+            Err(e) => {
+                eprintln!("Failed to run day {day}: {e:?}");
+                process::exit(1);
+            }
+        };
+
+        if results.is_empty() {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: {ANSI_ITALIC}not solved{ANSI_RESET}");
+            continue;
+        }
+
+        for part in [1, 2] {
+            let actual = results.iter().find(|r| r.part == part).and_then(|r| r.answer.as_deref());
+
+            let status = match answers::verify(expected.get(part), actual) {
+                Verification::Pass => format!("✔ {}", actual.unwrap_or_default()),
+                Verification::Missing => format!("? no answer recorded, got {}", actual.unwrap_or("no answer")),
+                Verification::Fail { expected, actual } => {
+                    failed += 1;
+                    format!("✖ expected {expected}, got {}", actual.as_deref().unwrap_or("no answer"))
+                }
+            };
+
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET} Part {part}: {status}");
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("\n{failed} part(s) do not match their recorded answer.");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,231 @@
+//! Module that reads the example manifest of each day in `data/<year>/examples/<day>.toml`.
+//! A manifest lists example files next to it, together with the answers expected for them.
+//! Examples and their answers can also be extracted from a downloaded puzzle description.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::template::answers::Answers;
+use crate::PuzzleId;
+
+pub const EXAMPLES_FOLDER: &str = "examples";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "invalid example manifest: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// An example file and the answers expected for it. Parts without an expected answer are not checked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Example {
+    /// Path of the example, relative to the manifest.
+    pub file: String,
+    #[serde(flatten)]
+    pub expected: Answers,
+}
+
+/// The examples of one day.
+///
+/// ```toml
+/// [[example]]
+/// file = "01.txt"
+/// part_one = "142"
+///
+/// [[example]]
+/// file = "01-2.txt"
+/// part_two = "281"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(rename = "example", default)]
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = toml::to_string(self).map_err(|e| Error::Parser(e.to_string()))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// The example with the given file name, added without expected answers if it is not listed yet.
+    pub fn example_mut(&mut self, file: &str) -> &mut Example {
+        let index = match self.examples.iter().position(|e| e.file == file) {
+            Some(index) => index,
+            None => {
+                self.examples.push(Example {
+                    file: file.into(),
+                    expected: Answers::default(),
+                });
+                self.examples.len() - 1
+            }
+        };
+        &mut self.examples[index]
+    }
+
+    /// The first example with an expected answer for `part`.
+    pub fn example_for(&self, part: u8) -> Option<&Example> {
+        self.examples.iter().find(|e| e.expected.get(part).is_some())
+    }
+}
+
+#[must_use]
+pub fn get_path(id: PuzzleId) -> PathBuf {
+    id.data_path(EXAMPLES_FOLDER, "toml")
+}
+
+/// Path of an example file of `id`.
+#[must_use]
+pub fn get_example_path(id: PuzzleId, example: &Example) -> PathBuf {
+    get_path(id).with_file_name(&example.file)
+}
+
+pub fn load(id: PuzzleId) -> Result<Manifest, Error> {
+    Manifest::load(get_path(id))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The example of one part of a puzzle description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Finds the example of every unlocked part in a puzzle description that was converted to Markdown.
+///
+/// The example is the first code block of a part, its answer the last emphasized code span (e.g. `` `*142*` ``),
+/// which is how the puzzles highlight the result of the example.
+pub fn parse_puzzle(markdown: &str) -> Vec<PuzzleExample> {
+    let parts = match markdown.find("--- Part Two ---") {
+        Some(index) => vec![&markdown[..index], &markdown[index..]],
+        None => vec![markdown],
+    };
+
+    parts
+        .into_iter()
+        .map(|part| PuzzleExample {
+            input: find_code_block(part),
+            answer: find_emphasized_code(part),
+        })
+        .collect()
+}
+
+fn find_code_block(markdown: &str) -> Option<String> {
+    let mut block: Vec<&str> = markdown
+        .lines()
+        .skip_while(|line| !line.starts_with("```"))
+        .skip(1)
+        .take_while(|line| !line.starts_with("```"))
+        .collect();
+
+    // code blocks end with an empty line before the closing fence.
+    if block.last().is_some_and(|line| line.is_empty()) {
+        block.pop();
+    }
+
+    if block.is_empty() {
+        return None;
+    }
+    Some(block.join("\n") + "\n")
+}
+
+fn find_emphasized_code(markdown: &str) -> Option<String> {
+    ["`*", "*`"]
+        .iter()
+        .filter_map(|open| {
+            let close = open.chars().rev().collect::<String>();
+            let start = markdown.rfind(open)? + open.len();
+            let end = start + markdown[start..].find(&close)?;
+            Some((start, &markdown[start..end]))
+        })
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, answer)| answer.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_example_path, parse_puzzle, Manifest, PuzzleExample};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn parses_manifest() {
+        let manifest = Manifest::parse("[[example]]\nfile = \"01.txt\"\npart_one = \"142\"\n\n[[example]]\nfile = \"01-2.txt\"\npart_two = \"281\"\n").unwrap();
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].expected.get(1), Some("142"));
+        assert_eq!(manifest.examples[0].expected.get(2), None);
+        assert_eq!(manifest.examples[1].expected.get(2), Some("281"));
+
+        let path = get_example_path(PuzzleId::new(year!(2023), day!(1)), &manifest.examples[1]);
+        assert_eq!(path.to_str(), Some("data/2023/examples/01-2.txt"));
+    }
+
+    #[test]
+    fn rejects_examples_without_file() {
+        assert!(Manifest::parse("[[example]]\npart_one = \"1\"\n").is_err());
+        assert_eq!(Manifest::parse("").unwrap(), Manifest::default());
+    }
+
+    #[test]
+    fn roundtrips_manifest() {
+        let mut manifest = Manifest::default();
+        manifest.example_mut("01.txt").expected.set(1, "142");
+        manifest.example_mut("01.txt").expected.set(2, "281");
+        assert_eq!(manifest.examples.len(), 1);
+
+        let toml = toml::to_string(&manifest).unwrap();
+        assert_eq!(toml, "[[example]]\nfile = \"01.txt\"\npart_one = \"142\"\npart_two = \"281\"\n");
+        assert_eq!(Manifest::parse(&toml).unwrap(), manifest);
+    }
+
+    #[test]
+    fn parses_examples_from_puzzle() {
+        let puzzle = "\\--- Day 1: Trebuchet?! ---\n----------\n\nFor example:\n\n```\n1abc2\npqr3stu8vwx\n\n```\n\nValues are `12` and `38`. Adding these together produces `*50*`.\n\n\\--- Part Two ---\n----------\n\nNow *again*, producing *`281`*.";
+        assert_eq!(
+            parse_puzzle(puzzle),
+            vec![
+                PuzzleExample {
+                    input: Some("1abc2\npqr3stu8vwx\n".into()),
+                    answer: Some("50".into()),
+                },
+                PuzzleExample {
+                    input: None,
+                    answer: Some("281".into()),
+                },
+            ]
+        );
+        assert_eq!(parse_puzzle("Nothing here."), vec![PuzzleExample::default()]);
+    }
+}
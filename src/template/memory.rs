@@ -0,0 +1,161 @@
+//! Module that measures heap allocations of solution parts.
+//! The counting allocator is only installed with the `memory` feature, measurements are [`None`] otherwise.
+
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and keeps track of the number of allocations and of the bytes in use.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(size: usize) {
+        let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // a reallocation counts as an allocation of the new size.
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            Self::shrink(layout.size());
+            Self::grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/* -------------------------------------------------------------------------- */
+
+/// Heap usage of one run of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// Most bytes allocated at the same time, on top of what was allocated before the run.
+    pub peak_bytes: u64,
+    pub allocations: u64,
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "peak {}, {} allocations", format_bytes(self.peak_bytes), self.allocations)
+    }
+}
+
+/// Whether allocations are counted, i.e. the crate was built with the `memory` feature.
+pub const fn is_enabled() -> bool {
+    cfg!(feature = "memory")
+}
+
+/// Runs `func` and measures its heap usage, if allocations are counted.
+/// Measurements are global, so other threads allocating at the same time are included.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    if !is_enabled() {
+        return (func(), None);
+    }
+
+    let base_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+    let base_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    PEAK_BYTES.store(base_bytes, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = MemoryStats {
+        peak_bytes: (PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(base_bytes)) as u64,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - base_allocations,
+    };
+
+    (result, Some(stats))
+}
+
+/// Formats `bytes` with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure, MemoryStats};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GiB");
+
+        let stats = MemoryStats {
+            peak_bytes: 2048,
+            allocations: 3,
+        };
+        assert_eq!(stats.to_string(), "peak 2.0 KiB, 3 allocations");
+    }
+
+    #[test]
+    fn measures_allocations_if_enabled() {
+        let (len, stats) = measure(|| vec![0_u8; 4096].len());
+        assert_eq!(len, 4096);
+
+        match stats {
+            Some(stats) => {
+                assert!(stats.allocations >= 1);
+                assert!(stats.peak_bytes >= 4096);
+            }
+            None => assert!(!super::is_enabled()),
+        }
+    }
+}
@@ -1,48 +1,201 @@
-use crate::Day;
-use std::{env, fs};
+use crate::PuzzleId;
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod examples;
+pub mod memory;
 pub mod readme_benchmarks;
 pub mod runner;
+pub mod solution;
+pub mod stats;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// An input that could not be read.
+#[derive(Debug)]
+pub enum ReadError {
+    File { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::File { source, .. } | ReadError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::File { path, source } => write!(f, "could not read \"{}\": {source}", path.display()),
+            ReadError::Stdin(source) => write!(f, "could not read input from stdin: {source}"),
+        }
+    }
+}
+
+/// Where a solution reads its input from, set with `--input <path>`. A path of `-` reads from stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The input in `data/<year>/inputs`.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the source from the `--input` argument of the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let Some(index) = args.iter().position(|x| x == "--input") else {
+            return Self::Default;
+        };
+
+        match args.get(index + 1) {
+            Some(value) => Self::new(Some(value)),
+            None => {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --input <path>");
+                process::exit(1);
+            }
+        }
+    }
+
+    /// The source for the value of an `--input` argument, if one was passed.
+    pub fn new(value: Option<&str>) -> Self {
+        match value {
+            None => Self::Default,
+            Some("-") => Self::Stdin,
+            Some(path) => Self::Path(path.into()),
+        }
+    }
+
+    /// Reads the input of `id` from this source.
+    pub fn read(&self, id: PuzzleId) -> Result<String, ReadError> {
+        match self {
+            InputSource::Default => try_read_file("inputs", id),
+            InputSource::Path(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(ReadError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Reads the file at `path` to a string.
+pub fn read_path(path: impl AsRef<Path>) -> Result<String, ReadError> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|source| ReadError::File { path: path.into(), source })
+}
+
+/// Reads a text file of `data/<year>/<folder>` to a string.
+pub fn try_read_file(folder: &str, id: PuzzleId) -> Result<String, ReadError> {
+    read_path(id.data_path(folder, "txt"))
+}
+
+/// Reads a text file of `data/<year>/<folder>` to string, appending a part suffix. E.g. like `01-2.txt`.
+pub fn try_read_file_part(folder: &str, id: PuzzleId, part: u8) -> Result<String, ReadError> {
+    read_path(Path::new("data").join(id.year.to_string()).join(folder).join(format!("{}-{part}.txt", id.day)))
+}
+
+/// Helper function that reads a text file of `data/<year>/<folder>` to a string. Panics with the attempted path if the file can't be read.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, id: PuzzleId) -> String {
+    try_read_file(folder, id).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Panics with the attempted path if the file can't be read.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file_part(folder: &str, id: PuzzleId, part: u8) -> String {
+    try_read_file_part(folder, id, part).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+/// The input is read from `data/<year>/inputs` unless another one is passed with `--input <path>` (`-` for stdin).
+///
+/// The year defaults to `AOC_YEAR` at compile time if only the day is passed.
+///
+/// Also creates `solution()`, which implements [`Solution`](solution::Solution) with the day's `part_one` and `part_two`,
+/// so that the day can be run in-process by the `advent_of_code` binary.
+///
+/// Pass `parse = <function>` to parse the input once, e.g. `solution!(2023, 5, parse = parse_almanac)`. Both parts then
+/// take a reference to its output instead of the input, and parsing is timed separately from the parts.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+    (@impl $year:expr, $day:expr $(, $parse:path)?) => {
+        /// The current puzzle.
+        pub const DAY: advent_of_code::PuzzleId = advent_of_code::PuzzleId::new($year, advent_of_code::day!($day));
+
+        const _ASSERT_DAY_OF_EVENT: () = assert!(
+            DAY.day.into_inner() <= DAY.year.last_day().into_inner(),
+            concat!("day `", $day, "` is not part of the event"),
+        );
+
+        /// Both parts of the current puzzle.
+        pub fn solution() -> impl advent_of_code::template::solution::Solution {
+            $crate::solution!(@solution $($parse)?)
+        }
 
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let input = advent_of_code::template::InputSource::from_args().read(DAY).unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+            advent_of_code::template::runner::run_solution(&solution(), &input);
         }
     };
+    (@solution) => {
+        advent_of_code::template::solution::SolutionFns::unparsed(DAY, |input: &String| part_one(input), |input: &String| part_two(input))
+    };
+    (@solution $parse:path) => {
+        advent_of_code::template::solution::SolutionFns::new(DAY, $parse, part_one, part_two)
+    };
+    ($day:expr, parse = $parse:path) => {
+        $crate::solution!(@impl advent_of_code::Year::__from_env(option_env!("AOC_YEAR")), $day, $parse);
+    };
+    ($year:expr, $day:expr, parse = $parse:path) => {
+        $crate::solution!(@impl advent_of_code::year!($year), $day, $parse);
+    };
+    ($day:expr) => {
+        $crate::solution!(@impl advent_of_code::Year::__from_env(option_env!("AOC_YEAR")), $day);
+    };
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl advent_of_code::year!($year), $day);
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{try_read_file, InputSource, ReadError};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn read_errors_include_path() {
+        let id = PuzzleId::new(year!(2015), day!(25));
+        match try_read_file("missing", id) {
+            Err(e @ ReadError::File { .. }) => assert!(e.to_string().starts_with("could not read \"data/2015/missing/25.txt\":")),
+            res => panic!("unexpected result: {res:?}"),
+        }
+    }
+
+    #[test]
+    fn reads_input_from_path() {
+        let id = PuzzleId::new(year!(2023), day!(2));
+        let input = InputSource::Path("data/2023/examples/02.txt".into()).read(id).unwrap();
+        assert!(input.starts_with("Game 1:"));
+    }
 }
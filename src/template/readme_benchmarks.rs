@@ -2,7 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::memory::MemoryStats;
+use crate::template::stats::Stats;
+use crate::{Day, PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -20,9 +22,20 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
+    pub year: Year,
     pub day: Day,
+    /// Time of the shared `parse` step, if the solution has one.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Sample statistics of the `parse` step and each part, if they were benched.
+    pub parse_stats: Option<Stats>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    /// Heap usage of the `parse` step and each part, if allocations were counted with the `memory` feature.
+    pub parse_memory: Option<MemoryStats>,
+    pub part_1_memory: Option<MemoryStats>,
+    pub part_2_memory: Option<MemoryStats>,
     pub total_nanos: f64,
 }
 
@@ -32,8 +45,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./{}", PuzzleId::new(year, day).bin_path().display())
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -61,23 +74,55 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    // the parse and memory columns are only shown if a solution has a `parse` step or allocations were counted.
+    let has_parse = timings.iter().any(|timing| timing.parse.is_some());
+    let has_memory = timings.iter().any(|timing| timing.parse_memory.is_some() || timing.part_1_memory.is_some() || timing.part_2_memory.is_some());
+
+    let mut columns = vec!["Day"];
+    if has_parse {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+    if has_memory && has_parse {
+        columns.push("Parse memory");
+    }
+    if has_memory {
+        columns.extend(["Part 1 memory", "Part 2 memory"]);
+    }
+
+    let alignment: Vec<&str> = columns.iter().map(|_| ":---:").collect();
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", columns.join(" | ")),
+        format!("| {}  |", alignment.join(" | ")),
     ];
 
+    // days are only prefixed with their year if the table spans several years.
+    let is_multi_year = timings.windows(2).any(|w| w[0].year != w[1].year);
+
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        let path = get_path_for_bin(timing.year, timing.day);
+        let label = if is_multi_year {
+            format!("{} Day {}", timing.year, timing.day.into_inner())
+        } else {
+            format!("Day {}", timing.day.into_inner())
+        };
+
+        let mut cells = vec![format!("[{label}]({path})")];
+        if has_parse {
+            cells.push(code_cell(timing.parse));
+        }
+        cells.extend([code_cell(timing.part_1), code_cell(timing.part_2)]);
+        if has_memory && has_parse {
+            cells.push(code_cell(timing.parse_memory.map(|m| m.to_string())));
+        }
+        if has_memory {
+            cells.extend([timing.part_1_memory, timing.part_2_memory].map(|memory| code_cell(memory.map(|m| m.to_string()))));
+        }
+
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
@@ -87,6 +132,10 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     lines.join("\n")
 }
 
+fn code_cell(value: Option<String>) -> String {
+    format!("`{}`", value.unwrap_or_else(|| "-".into()))
+}
+
 fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -105,26 +154,51 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use crate::template::memory::MemoryStats;
+    use crate::{day, year};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
+                year: year!(2023),
                 day: day!(1),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
+                parse_stats: None,
+                part_1_stats: None,
+                part_2_stats: None,
+                parse_memory: None,
+                part_1_memory: None,
+                part_2_memory: None,
                 total_nanos: 3e+10,
             },
             Timings {
+                year: year!(2023),
                 day: day!(2),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
+                parse_stats: None,
+                part_1_stats: None,
+                part_2_stats: None,
+                parse_memory: None,
+                part_1_memory: None,
+                part_2_memory: None,
                 total_nanos: 7e+10,
             },
             Timings {
+                year: year!(2023),
                 day: day!(4),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
+                parse_stats: None,
+                part_1_stats: None,
+                part_2_stats: None,
+                parse_memory: None,
+                part_1_memory: None,
+                part_2_memory: None,
                 total_nanos: 9e+10,
             },
         ]
@@ -172,9 +246,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023/04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +257,53 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn labels_days_with_years_if_several_years() {
+        let mut timings = get_mock_timings();
+        timings[0].year = year!(2022);
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [2022 Day 1](./src/bin/2022/01.rs) | `10ms` | `20ms` |"));
+        assert!(s.contains("| [2023 Day 2](./src/bin/2023/02.rs) | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn adds_parse_column_if_a_solution_parses() {
+        let mut timings = get_mock_timings();
+        timings[1].parse = Some("5ms".into());
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023/01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023/02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn adds_memory_columns_if_allocations_were_counted() {
+        let mut timings = get_mock_timings();
+        timings[0].part_1_memory = Some(MemoryStats {
+            peak_bytes: 1536,
+            allocations: 12,
+        });
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Part 1 memory | Part 2 memory |"));
+        assert!(s.contains("| :---: | :---: | :---: | :---: | :---:  |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023/01.rs) | `10ms` | `20ms` | `peak 1.5 KiB, 12 allocations` | `-` |"));
+    }
+
+    #[test]
+    fn adds_parse_memory_column_if_a_solution_parses() {
+        let mut timings = get_mock_timings();
+        timings[1].parse = Some("5ms".into());
+        timings[1].parse_memory = Some(MemoryStats {
+            peak_bytes: 2048,
+            allocations: 3,
+        });
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 | Parse memory | Part 1 memory | Part 2 memory |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023/02.rs) | `5ms` | `30ms` | `40ms` | `peak 2.0 KiB, 3 allocations` | `-` | `-` |"));
+    }
 }
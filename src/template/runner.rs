@@ -1,68 +1,473 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
-use anyhow::Result;
+use crate::template::memory::{self, MemoryStats};
+use crate::template::solution::Solution;
+use crate::template::stats::Stats;
+use crate::template::submissions::{self, Submission, SubmissionOutcome};
+use crate::template::{answers, aoc_client, ANSI_ITALIC, ANSI_RESET};
+use crate::PuzzleId;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Result<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// How a solution binary reports its results, selected with `--format <human|json>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colored, human-readable output with live progress.
+    #[default]
+    Human,
+    /// One JSON object per part and line, see [`PartResult`].
+    Json,
+}
+
+impl OutputFormat {
+    /// Reads the format from the `--format` argument of the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let Some(index) = args.iter().position(|x| x == "--format") else {
+            return Self::default();
+        };
+
+        match args.get(index + 1).map(|x| x.parse()) {
+            Some(Ok(format)) => format,
+            Some(Err(err)) => {
+                eprintln!("{err}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --format json");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl std::error::Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of the output formats \"human\" or \"json\".")
+    }
+}
+
+/// The `part` of the [`PartResult`] that reports the shared `parse` step of a solution. It has no answer.
+pub const PARSE_STEP: u8 = 0;
+
+/// The label of a part in the output, e.g. `Part 1` or `Parse`.
+pub fn part_label(part: u8) -> String {
+    if part == PARSE_STEP {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// The machine-readable result of one solution part, emitted as a single JSON line in [`OutputFormat::Json`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The answer, or [`None`] if the part returned an error.
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    /// Median duration if the part was benched with `--time`, otherwise the duration of the single run.
+    pub duration_ns: u64,
+    pub samples: u64,
+    #[serde(default)]
+    pub stats: Option<Stats>,
+    /// Heap usage of the first run, if allocations are counted with the `memory` feature.
+    #[serde(default)]
+    pub memory: Option<MemoryStats>,
+    /// Whether the part was cancelled because it exceeded the `--timeout` of `solve` or `all`.
+    #[serde(default)]
+    pub timed_out: bool,
+}
+
+/// Passed to a solution binary to make it report [`Progress`] in [`OutputFormat::Json`].
+pub const PROGRESS_FLAG: &str = "--progress";
+
+/// Reports that a step of a solution, i.e. its `parse` step or a part, started or has its answer. Emitted as a single
+/// JSON line before the step's [`PartResult`] with [`PROGRESS_FLAG`], so that the process running the binary can tell
+/// which step is running and stop it if it takes too long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub event: ProgressEvent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressEvent {
+    /// The step starts computing its answer.
+    Started,
+    /// The step computed its answer and is benched next if `--time` was passed.
+    Answered,
+}
+
+impl Progress {
+    fn report(id: PuzzleId, part: u8, event: ProgressEvent) {
+        if !env::args().any(|x| x == PROGRESS_FLAG) {
+            return;
+        }
+
+        let progress = Self {
+            year: id.year.into_inner(),
+            day: id.day.into_inner(),
+            part,
+            event,
+        };
+        match serde_json::to_string(&progress) {
+            Ok(json) => println!("{json}"),
+            Err(err) => eprintln!("Failed to serialize progress: {err}"),
+        }
+    }
+}
+
+/// How long and how often a part is run with `--time`, set with `--warmup <ms>`, `--bench-time <ms>` and `--iterations <n>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent running the part before recording samples, defaults to 100ms.
+    pub warmup_ms: Option<u64>,
+    /// Time budget for recording samples, defaults to 1s. At least 10 and at most 10,000 samples are recorded.
+    pub budget_ms: Option<u64>,
+    /// Records exactly this many samples, ignoring the time budget.
+    pub iterations: Option<u64>,
+}
+
+impl BenchConfig {
+    const MIN_ITERATIONS: u64 = 10;
+    const MAX_ITERATIONS: u64 = 10_000;
+
+    /// Reads the bench flags of the current process.
+    pub fn from_args() -> Self {
+        Self::parse(&mut pico_args::Arguments::from_env()).unwrap_or_else(|err| {
+            eprintln!("Unexpected command-line input: {err}.");
+            process::exit(1);
+        })
+    }
+
+    /// Takes the bench flags out of `args`.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            warmup_ms: args.opt_value_from_str("--warmup")?,
+            budget_ms: args.opt_value_from_str("--bench-time")?,
+            iterations: args.opt_value_from_str("--iterations")?,
+        })
+    }
+
+    /// The flags that reproduce this config when passed to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        for (flag, value) in [("--warmup", self.warmup_ms), ("--bench-time", self.budget_ms), ("--iterations", self.iterations)] {
+            if let Some(value) = value {
+                args.push(flag.to_string());
+                args.push(value.to_string());
+            }
+        }
+        args
+    }
+
+    fn warmup(&self) -> Duration {
+        Duration::from_millis(self.warmup_ms.unwrap_or(100))
+    }
+
+    fn budget(&self) -> Duration {
+        Duration::from_millis(self.budget_ms.unwrap_or(1000))
+    }
+}
+
+impl PartResult {
+    fn new<T: Display>(result: &Result<T>, id: PuzzleId, part: u8, duration: &Duration, stats: Option<Stats>, memory: Option<MemoryStats>) -> Self {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer.to_string()), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+
+        Self {
+            year: id.year.into_inner(),
+            day: id.day.into_inner(),
+            part,
+            answer,
+            error,
+            duration_ns: u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+            samples: stats.map_or(1, |s| s.samples),
+            stats,
+            memory,
+            timed_out: false,
+        }
+    }
+
+    /// The result of a part that was cancelled after running for `timeout`.
+    pub fn timeout(id: PuzzleId, part: u8, timeout: Duration) -> Self {
+        let mut result = Self::new::<String>(&Err(anyhow!("timed out after {timeout:.1?}")), id, part, &timeout, None, None);
+        result.timed_out = true;
+        result
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+
+    /// Prints the result the same way a solution binary does in [`OutputFormat::Human`].
+    pub fn print(&self) {
+        let result = match (&self.answer, &self.error) {
+            (Some(answer), _) => Ok(answer.as_str()),
+            (None, None) if self.part == PARSE_STEP => Ok(PARSED),
+            (None, error) => Err(anyhow!("{}", error.as_deref().unwrap_or("no answer"))),
+        };
+        print_result(&result, &part_label(self.part), &format_duration(&self.duration(), self.samples));
+        self.print_details();
+    }
+
+    /// Prints the sample statistics and heap usage of a benched part.
+    fn print_details(&self) {
+        if let Some(stats) = &self.stats {
+            println!("  {ANSI_ITALIC}{stats}{ANSI_RESET}");
+            if let Some(memory) = &self.memory {
+                println!("  {ANSI_ITALIC}{memory}{ANSI_RESET}");
+            }
+        }
+    }
+
+    /// Prints the result in `format`. `result` is what is displayed for it in [`OutputFormat::Human`].
+    fn report<T: Display>(&self, result: &Result<T>, format: OutputFormat) {
+        match format {
+            OutputFormat::Human => {
+                print_result(result, &part_label(self.part), &format_duration(&self.duration(), self.samples));
+                self.print_details();
+            }
+            OutputFormat::Json => self.print_json(),
+        }
+    }
+
+    /// Prints the result as one line of JSON, like a bin run with `--format json` does.
+    pub fn print_json(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => println!("{json}"),
+            Err(err) => eprintln!("Failed to serialize result: {err}"),
+        }
+    }
+}
+
+/// Displayed instead of the parsed input, which has no answer to show.
+const PARSED: &str = "✔";
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Parses the input once and runs both parts of `solution` on it. A parse error, or a panic while parsing, is reported
+/// as the result of both parts.
+///
+/// If the solution has a `parse` step, it is timed on its own and reported as [`PARSE_STEP`] before the parts.
+pub fn run_solution<S: Solution>(solution: &S, input: &str) -> Vec<PartResult> {
+    let id = solution.id();
+    let mut results = vec![];
+
+    let parsed = if solution.has_parse() {
+        let (parsed, result) = run_parse(solution, input);
+        results.push(result);
+        parsed
+    } else {
+        solution.parse(input)
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    match parsed {
+        Ok(parsed) => {
+            results.push(run_part(|input| solution.part_one(input), &parsed, id, 1));
+            results.push(run_part(|input| solution.part_two(input), &parsed, id, 2));
+        }
+        Err(err) => {
+            let message = err.to_string();
+            results.extend([1, 2].map(|part| run_part(|()| Err::<String, _>(anyhow!("{message}")), (), id, part)));
+        }
+    }
+
+    results
+}
+
+/// Runs the `parse` step of `solution` like a part and returns the parsed input together with its result.
+fn run_parse<S: Solution>(solution: &S, input: &str) -> (Result<S::Input>, PartResult) {
+    run_step(|input| solution.parse(input), input, solution.id(), PARSE_STEP, |_| PARSED.to_string())
+}
+
+/// Runs one part of a solution and reports its result. A panic in `func` is reported as an error of the part, so that
+/// the next part still runs.
+pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Result<T>, input: I, id: PuzzleId, part: u8) -> PartResult {
+    let (result, part_result) = run_step(func, input, id, part, ToString::to_string);
 
     if let Ok(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, id, part);
+    }
+
+    part_result
+}
+
+/// Times, measures and reports one step of a solution, i.e. its `parse` step or a part. `display` turns the output of
+/// `func` into what is shown for it, the `parse` step has no answer of its own. A panic in `func` is reported as an
+/// error of the step.
+fn run_step<I: Clone, T>(func: impl Fn(I) -> Result<T>, input: I, id: PuzzleId, part: u8, display: impl Fn(&T) -> String) -> (Result<T>, PartResult) {
+    let format = OutputFormat::from_args();
+    let label = part_label(part);
+    let shown = |result: &Result<T>| result.as_ref().map(&display).map_err(|err| anyhow!("{err}"));
+
+    Progress::report(id, part, ProgressEvent::Started);
+    let (result, duration, stats, memory) = run_timed(|input| catch_panic(|| func(input)), input, format, |result| {
+        Progress::report(id, part, ProgressEvent::Answered);
+        if format == OutputFormat::Human {
+            print_result(&shown(result), &label, "");
+        }
+    });
+
+    let mut part_result = PartResult::new(&shown(&result), id, part, &duration, stats, memory);
+    if part == PARSE_STEP {
+        part_result.answer = None;
+    }
+    part_result.report(&shown(&result), format);
+
+    (result, part_result)
+}
+
+thread_local! {
+    /// Whether a panic on this thread is caught by [`catch_panic`], which then reports it instead of the panic hook.
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+    /// Where the last caught panic on this thread happened.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that records the location of caught panics and leaves all other panics to the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANIC.get() {
+                PANIC_LOCATION.set(info.location().map(ToString::to_string));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `func` and turns an unwinding panic into an error with the panic message and location.
+fn catch_panic<T>(func: impl FnOnce() -> Result<T>) -> Result<T> {
+    install_panic_hook();
+
+    let was_catching = CATCHING_PANIC.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING_PANIC.set(was_catching);
+
+    result.unwrap_or_else(|payload| {
+        let message = panic_message(payload.as_ref());
+        match PANIC_LOCATION.take() {
+            Some(location) => Err(anyhow!("panicked at {location}: {message}")),
+            None => Err(anyhow!("panicked: {message}")),
+        }
+    })
+}
+
+/// The message of a panic, which is a `&str` or a `String` unless the panic was raised with a custom payload.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        _ => "Box<dyn Any>",
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether the `--time` flag is passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched according to [`BenchConfig`] and the median duration is reported.
+///
+/// The heap usage of the first run is measured if allocations are counted with the `memory` feature.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    format: OutputFormat,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> (T, Duration, Option<Stats>, Option<MemoryStats>) {
+    let cloned = input.clone();
+    let ((result, base_time), memory) = memory::measure(|| {
+        let timer = Instant::now();
+        let result = func(cloned);
+        (result, timer.elapsed())
+    });
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
+    if !std::env::args().any(|x| x == "--time") {
+        return (result, base_time, None, memory);
+    }
 
-    (result, run.0, run.1)
+    match bench(func, input, &BenchConfig::from_args(), format) {
+        Some(stats) => (result, stats.median(), Some(stats), memory),
+        None => (result, base_time, None, memory),
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, config: &BenchConfig, format: OutputFormat) -> Option<Stats> {
+    if format == OutputFormat::Human {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    // warm up caches and branch predictors before recording anything.
+    let warmup = Instant::now();
+    while warmup.elapsed() < config.warmup() {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let budget = Instant::now();
+
+    loop {
+        let done = match config.iterations {
+            Some(iterations) => timers.len() as u64 >= iterations,
+            None => {
+                let recorded = timers.len() as u64;
+                recorded >= BenchConfig::MAX_ITERATIONS || (recorded >= BenchConfig::MIN_ITERATIONS && budget.elapsed() >= config.budget())
+            }
+        };
+
+        if done {
+            break;
+        }
 
-    for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -70,22 +475,10 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
-
-fn format_duration(duration: &Duration, samples: u128) -> String {
+fn format_duration(duration: &Duration, samples: u64) -> String {
     if samples == 1 {
         format!(" ({duration:.1?})")
     } else {
@@ -129,14 +522,12 @@ fn print_result<T: Display>(result: &Result<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if `--submit <part>` matches `part`.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    id: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<String, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -159,11 +550,88 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let answer = result.to_string();
+
+    if !args.contains(&"--force".into()) {
+        let rejection = match submissions::load(submissions::LOG_PATH) {
+            Ok(history) => submissions::check(&history, id, part, &answer).err(),
+            Err(e) => {
+                eprintln!("Failed to read submissions log: {e}");
+                None
+            }
+        };
+
+        if let Some(rejection) = rejection {
+            eprintln!("Not submitting: {rejection} Pass --force to submit anyway.");
+            return None;
+        }
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let response = aoc_client::submit(id, part, &answer);
+
+    match &response {
+        Ok(message) => match SubmissionOutcome::parse(message) {
+            Some(outcome) => {
+                println!("{outcome}");
+
+                if let Err(e) = submissions::record(&Submission::new(id, part, &answer, outcome)) {
+                    eprintln!("Failed to log submission: {e}");
+                }
+
+                if outcome == SubmissionOutcome::Correct {
+                    match answers::record(id, part, &answer) {
+                        Ok(()) => println!("Recorded answer in {}.", answers::get_path(id).display()),
+                        Err(e) => eprintln!("Failed to record answer: {e}"),
+                    }
+                }
+            }
+            None => println!("{message}"),
+        },
+        Err(e) => eprintln!("Failed to submit answer: {e}"),
+    }
+
+    Some(response)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{catch_panic, run_solution, PARSE_STEP};
+    use crate::template::solution::SolutionFns;
+    use crate::{day, year, PuzzleId};
+    use anyhow::anyhow;
+
+    #[test]
+    fn reports_panics_as_errors() {
+        let err = catch_panic::<u32>(|| panic!("no path to {}", 42)).unwrap_err().to_string();
+        assert!(err.starts_with(&format!("panicked at {}:", file!())), "{err}");
+        assert!(err.ends_with(": no path to 42"), "{err}");
+
+        let err = catch_panic::<u32>(|| Err(anyhow!("not implemented"))).unwrap_err();
+        assert_eq!(err.to_string(), "not implemented");
+        assert_eq!(catch_panic(|| Ok(7)).unwrap(), 7);
+    }
+
+    #[test]
+    fn reports_panicking_parse_for_both_parts() {
+        let solution = SolutionFns::new(
+            PuzzleId::new(year!(2023), day!(5)),
+            |input: &str| Ok(input.lines().map(|line| line.parse::<u32>().expect("not a number")).collect::<Vec<_>>()),
+            |numbers: &Vec<u32>| Ok(numbers.iter().sum::<u32>()),
+            |numbers: &Vec<u32>| Ok(numbers.len()),
+        );
+
+        let results = run_solution(&solution, "1\nx\n");
+        assert_eq!(results.iter().map(|r| r.part).collect::<Vec<_>>(), vec![PARSE_STEP, 1, 2]);
+        for result in &results {
+            let error = result.error.as_deref().unwrap_or_default();
+            assert!(error.starts_with(&format!("panicked at {}:", file!())), "{error}");
+            assert!(error.contains("not a number"), "{error}");
+            assert_eq!(result.answer, None);
+        }
+
+        assert_eq!(run_solution(&solution, "1\n2\n")[1].answer.as_deref(), Some("3"));
+    }
 }
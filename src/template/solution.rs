@@ -0,0 +1,174 @@
+//! Module that describes solutions independently of their binaries, so that any day can be run in-process.
+
+use anyhow::Result;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use crate::template::runner::PartResult;
+use crate::PuzzleId;
+
+/// The solution of a puzzle. The input is parsed once and shared by both parts.
+///
+/// [`solution!`](crate::solution) implements this trait for the `part_one` and `part_two` functions of a day, see
+/// [`SolutionFns`].
+pub trait Solution {
+    /// The parsed input passed to both parts.
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn id(&self) -> PuzzleId;
+
+    /// Whether the solution has a `parse` step of its own, which is then timed separately from the parts.
+    fn has_parse(&self) -> bool {
+        true
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part_one(&self, input: &Self::Input) -> Result<Self::PartOne>;
+    fn part_two(&self, input: &Self::Input) -> Result<Self::PartTwo>;
+}
+
+/// Marks the input and output types of [`SolutionFns`] without owning values of them.
+type Types<I, A, B> = PhantomData<fn() -> (I, A, B)>;
+
+/// A [`Solution`] made of plain functions, which lets the output types be inferred.
+pub struct SolutionFns<P, F1, F2, I, A, B> {
+    id: PuzzleId,
+    parse: P,
+    part_one: F1,
+    part_two: F2,
+    has_parse: bool,
+    _types: Types<I, A, B>,
+}
+
+impl<P, F1, F2, I, A, B> SolutionFns<P, F1, F2, I, A, B>
+where
+    P: Fn(&str) -> Result<I>,
+    F1: Fn(&I) -> Result<A>,
+    F2: Fn(&I) -> Result<B>,
+{
+    pub fn new(id: PuzzleId, parse: P, part_one: F1, part_two: F2) -> Self {
+        Self {
+            id,
+            parse,
+            part_one,
+            part_two,
+            has_parse: true,
+            _types: PhantomData,
+        }
+    }
+}
+
+impl<F1, F2, A, B> SolutionFns<fn(&str) -> Result<String>, F1, F2, String, A, B>
+where
+    F1: Fn(&String) -> Result<A>,
+    F2: Fn(&String) -> Result<B>,
+{
+    /// A solution whose parts take the input as it is, without a `parse` step.
+    pub fn unparsed(id: PuzzleId, part_one: F1, part_two: F2) -> Self {
+        Self {
+            id,
+            parse: |input| Ok(input.to_string()),
+            part_one,
+            part_two,
+            has_parse: false,
+            _types: PhantomData,
+        }
+    }
+}
+
+impl<P, F1, F2, I, A, B> Solution for SolutionFns<P, F1, F2, I, A, B>
+where
+    P: Fn(&str) -> Result<I>,
+    F1: Fn(&I) -> Result<A>,
+    F2: Fn(&I) -> Result<B>,
+    A: Display,
+    B: Display,
+{
+    type Input = I;
+    type PartOne = A;
+    type PartTwo = B;
+
+    fn id(&self) -> PuzzleId {
+        self.id
+    }
+
+    fn has_parse(&self) -> bool {
+        self.has_parse
+    }
+
+    fn parse(&self, input: &str) -> Result<I> {
+        (self.parse)(input)
+    }
+
+    fn part_one(&self, input: &I) -> Result<A> {
+        (self.part_one)(input)
+    }
+
+    fn part_two(&self, input: &I) -> Result<B> {
+        (self.part_two)(input)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A solution that was compiled into the `advent_of_code` binary.
+pub struct RegisteredSolution {
+    pub id: PuzzleId,
+    /// Runs both parts on an input, like the solution's binary does, see [`run_solution`](crate::template::runner::run_solution).
+    pub run: fn(&str) -> Vec<PartResult>,
+}
+
+/// Every solution that was compiled into the `advent_of_code` binary with the `in_process` feature.
+/// The list is generated from `src/bin` by `build.rs`.
+pub struct Registry(pub &'static [RegisteredSolution]);
+
+impl Registry {
+    pub fn get(&self, id: PuzzleId) -> Option<&RegisteredSolution> {
+        self.0.iter().find(|solution| solution.id == id)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{RegisteredSolution, Registry, Solution, SolutionFns};
+    use crate::{day, year, PuzzleId};
+
+    const ID: PuzzleId = PuzzleId::new(year!(2023), day!(1));
+
+    #[test]
+    fn solves_with_functions() {
+        let solution = SolutionFns::new(
+            ID,
+            |input: &str| Ok(input.lines().map(str::len).collect::<Vec<_>>()),
+            |lengths: &Vec<usize>| Ok(lengths.iter().sum::<usize>()),
+            |lengths: &Vec<usize>| Ok(format!("{} lines", lengths.len())),
+        );
+
+        let input = solution.parse("ab\ncde\n").unwrap();
+        assert_eq!(solution.id(), ID);
+        assert!(solution.has_parse());
+        assert_eq!(solution.part_one(&input).unwrap(), 5);
+        assert_eq!(solution.part_two(&input).unwrap(), "2 lines");
+    }
+
+    #[test]
+    fn solves_without_parse() {
+        let solution = SolutionFns::unparsed(ID, |input: &String| Ok(input.len()), |input: &String| Ok(input.lines().count()));
+
+        let input = solution.parse("ab\ncde\n").unwrap();
+        assert!(!solution.has_parse());
+        assert_eq!(solution.part_one(&input).unwrap(), 7);
+        assert_eq!(solution.part_two(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn finds_registered_solutions() {
+        static REGISTRY: Registry = Registry(&[RegisteredSolution { id: ID, run: |_| vec![] }]);
+        assert!(REGISTRY.get(ID).is_some());
+        assert!(REGISTRY.get(PuzzleId::new(year!(2023), day!(2))).is_none());
+    }
+}
@@ -0,0 +1,138 @@
+//! Summary statistics over the samples recorded while benching a solution part.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// Distribution of sample durations, all values in nanoseconds.
+///
+/// Outliers are samples outside of Tukey's fences, i.e. more than `1.5 * IQR` below the first or above the third quartile.
+/// They are counted but not removed, so `min` and `max` still cover every sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: u64,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+    pub stddev_ns: u64,
+    pub p95_ns: u64,
+    pub p99_ns: u64,
+    pub outliers: u64,
+}
+
+impl Stats {
+    /// Computes the statistics of `samples`. Returns [`None`] if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<u64> = samples.iter().map(|d| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)).collect();
+        nanos.sort_unstable();
+
+        let count = nanos.len() as u64;
+        let sum: u128 = nanos.iter().map(|&n| u128::from(n)).sum();
+        let mean = u64::try_from(sum / u128::from(count)).unwrap_or(u64::MAX);
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = if count > 1 {
+            let mean = sum as f64 / count as f64;
+            nanos.iter().map(|&n| (n as f64 - mean).powi(2)).sum::<f64>() / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let fence = (q3 - q1) * 3 / 2;
+        let (low, high) = (q1.saturating_sub(fence), q3.saturating_add(fence));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some(Self {
+            samples: count,
+            mean_ns: mean,
+            median_ns: percentile(&nanos, 50.0),
+            min_ns: nanos[0],
+            max_ns: nanos[nanos.len() - 1],
+            stddev_ns: variance.sqrt().round() as u64,
+            p95_ns: percentile(&nanos, 95.0),
+            p99_ns: percentile(&nanos, 99.0),
+            outliers: nanos.iter().filter(|&&n| n < low || n > high).count() as u64,
+        })
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let d = Duration::from_nanos;
+        write!(
+            f,
+            "mean {:.1?} ± {:.1?}, min {:.1?}, max {:.1?}, p95 {:.1?}, p99 {:.1?}",
+            d(self.mean_ns),
+            d(self.stddev_ns),
+            d(self.min_ns),
+            d(self.max_ns),
+            d(self.p95_ns),
+            d(self.p99_ns)
+        )?;
+
+        match self.outliers {
+            0 => Ok(()),
+            1 => write!(f, ", 1 outlier"),
+            n => write!(f, ", {n} outliers"),
+        }
+    }
+}
+
+/// Nearest-rank percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{percentile, Stats};
+    use std::time::Duration;
+
+    fn samples(nanos: &[u64]) -> Vec<Duration> {
+        nanos.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = Stats::from_samples(&samples(&[10, 12, 11, 13, 9, 10, 11, 12, 10, 100])).unwrap();
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.mean_ns, 19);
+        assert_eq!(stats.median_ns, 11);
+        assert_eq!(stats.min_ns, 9);
+        assert_eq!(stats.max_ns, 100);
+        assert_eq!(stats.stddev_ns, 28);
+        assert_eq!(stats.p95_ns, 100);
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::from_samples(&samples(&[42])).unwrap();
+        assert_eq!((stats.median_ns, stats.p99_ns, stats.stddev_ns, stats.outliers), (42, 42, 0, 0));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn nearest_rank_percentiles() {
+        let sorted: Vec<u64> = (1..=100).collect();
+        assert_eq!(percentile(&sorted, 50.0), 50);
+        assert_eq!(percentile(&sorted, 95.0), 95);
+        assert_eq!(percentile(&sorted, 99.0), 99);
+        assert_eq!(percentile(&sorted, 0.0), 1);
+    }
+}
@@ -0,0 +1,330 @@
+//! Module that classifies responses to submitted answers and keeps a log of every submission in `data/submissions.jsonl`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
+
+pub const LOG_PATH: &str = "data/submissions.jsonl";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "invalid submissions log: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Whether a wrong answer was above or below the right one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect { hint: Option<Hint> },
+    /// The part was solved before, the answer was not checked.
+    AlreadySolved,
+    /// An answer was submitted too recently, the answer was not checked.
+    RateLimited { wait: Option<Duration> },
+}
+
+impl SubmissionOutcome {
+    /// Classifies the message of a response to a submission. Returns [`None`] if the message is not recognized.
+    pub fn parse(message: &str) -> Option<Self> {
+        // the website hard-wraps messages, so whitespace is normalized before matching.
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if message.contains("That's not the right answer") {
+            let hint = if message.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if message.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(Self::Incorrect { hint })
+        } else if message.contains("You don't seem to be solving the right level") {
+            Some(Self::AlreadySolved)
+        } else if message.contains("You gave an answer too recently") {
+            Some(Self::RateLimited { wait: parse_wait(&message) })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "✔ {ANSI_BOLD}That's the right answer!{ANSI_RESET}"),
+            Self::Incorrect { hint: Some(Hint::TooHigh) } => write!(f, "✖ {ANSI_BOLD}That's not the right answer{ANSI_RESET}, it is too high."),
+            Self::Incorrect { hint: Some(Hint::TooLow) } => write!(f, "✖ {ANSI_BOLD}That's not the right answer{ANSI_RESET}, it is too low."),
+            Self::Incorrect { hint: None } => write!(f, "✖ {ANSI_BOLD}That's not the right answer.{ANSI_RESET}"),
+            Self::AlreadySolved => write!(f, "This part is already solved."),
+            Self::RateLimited { wait: Some(wait) } => write!(f, "⏳ Answered too recently, wait {}s before submitting again.", wait.as_secs()),
+            Self::RateLimited { wait: None } => write!(f, "⏳ Answered too recently, wait a bit before submitting again."),
+        }
+    }
+}
+
+/// Parses the remaining wait time from messages like "You have 1m 5s left to wait."
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    message[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An entry of the submissions log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub outcome: SubmissionOutcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    pub fn new(id: PuzzleId, part: u8, answer: &str, outcome: SubmissionOutcome) -> Self {
+        Self {
+            year: id.year.into_inner(),
+            day: id.day.into_inner(),
+            part,
+            answer: answer.into(),
+            outcome,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+        }
+    }
+}
+
+/// Why an answer should not be submitted, based on earlier submissions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The exact same answer was already rejected.
+    KnownWrong,
+    /// The answer is not below an earlier answer that was too high.
+    AboveTooHigh { bound: String },
+    /// The answer is not above an earlier answer that was too low.
+    BelowTooLow { bound: String },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::KnownWrong => write!(f, "this answer was already submitted and is not right."),
+            Rejection::AboveTooHigh { bound } => write!(f, "{bound} was too high, so this answer is too."),
+            Rejection::BelowTooLow { bound } => write!(f, "{bound} was too low, so this answer is too."),
+        }
+    }
+}
+
+/// Checks `answer` against the earlier submissions of the same puzzle and part.
+///
+/// Numeric answers are also checked against the bounds given by earlier _too high_ and _too low_ hints.
+pub fn check(history: &[Submission], id: PuzzleId, part: u8, answer: &str) -> Result<(), Rejection> {
+    let previous = history
+        .iter()
+        .filter(|s| s.year == id.year.into_inner() && s.day == id.day.into_inner() && s.part == part);
+    let value: Option<i128> = answer.trim().parse().ok();
+
+    for submission in previous {
+        let SubmissionOutcome::Incorrect { hint } = submission.outcome else {
+            continue;
+        };
+
+        if submission.answer == answer {
+            return Err(Rejection::KnownWrong);
+        }
+
+        let (Some(value), Ok(bound)) = (value, submission.answer.trim().parse::<i128>()) else {
+            continue;
+        };
+
+        match hint {
+            Some(Hint::TooHigh) if value >= bound => {
+                return Err(Rejection::AboveTooHigh {
+                    bound: submission.answer.clone(),
+                })
+            }
+            Some(Hint::TooLow) if value <= bound => {
+                return Err(Rejection::BelowTooLow {
+                    bound: submission.answer.clone(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads all submissions from the log at `path`. A missing log is treated as empty.
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Submission>, Error> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    content.lines().filter(|line| !line.trim().is_empty()).map(|line| Ok(serde_json::from_str(line)?)).collect()
+}
+
+/// Appends `submission` to the log at `path`.
+pub fn append(path: impl AsRef<Path>, submission: &Submission) -> Result<(), Error> {
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(submission)?)?;
+    Ok(())
+}
+
+/// Appends `submission` to the log in `data/`.
+pub fn record(submission: &Submission) -> Result<(), Error> {
+    append(LOG_PATH, submission)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, Hint, Rejection, Submission, SubmissionOutcome};
+    use crate::{day, year, PuzzleId};
+    use std::time::Duration;
+
+    fn submission(part: u8, answer: &str, outcome: SubmissionOutcome) -> Submission {
+        Submission {
+            year: 2023,
+            day: 1,
+            part,
+            answer: answer.into(),
+            outcome,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        let parse = SubmissionOutcome::parse;
+        assert_eq!(parse("That's the right answer!  You are one gold star closer."), Some(SubmissionOutcome::Correct));
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high.  If you're stuck..."),
+            Some(SubmissionOutcome::Incorrect { hint: Some(Hint::TooHigh) })
+        );
+        assert_eq!(
+            parse("That's not the right answer;\nyour answer is too low."),
+            Some(SubmissionOutcome::Incorrect { hint: Some(Hint::TooLow) })
+        );
+        assert_eq!(parse("That's not the right answer.  If you're stuck..."), Some(SubmissionOutcome::Incorrect { hint: None }));
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(SubmissionOutcome::AlreadySolved)
+        );
+        assert_eq!(parse("Something else entirely."), None);
+    }
+
+    #[test]
+    fn parses_rate_limit_wait() {
+        let message = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.";
+        assert_eq!(
+            SubmissionOutcome::parse(message),
+            Some(SubmissionOutcome::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            })
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("You gave an answer too recently."),
+            Some(SubmissionOutcome::RateLimited { wait: None })
+        );
+    }
+
+    #[test]
+    fn serializes_log_entries() {
+        let submission = Submission {
+            year: 2023,
+            day: 1,
+            part: 2,
+            answer: "42".into(),
+            outcome: SubmissionOutcome::Incorrect { hint: Some(Hint::TooLow) },
+            timestamp: 7,
+        };
+        let json = serde_json::to_string(&submission).unwrap();
+        assert_eq!(json, r#"{"year":2023,"day":1,"part":2,"answer":"42","outcome":"incorrect","hint":"too_low","timestamp":7}"#);
+        assert_eq!(serde_json::from_str::<Submission>(&json).unwrap(), submission);
+    }
+
+    #[test]
+    fn rejects_known_wrong_and_out_of_bounds_answers() {
+        let history = [
+            submission(1, "100", SubmissionOutcome::Incorrect { hint: Some(Hint::TooHigh) }),
+            submission(1, "20", SubmissionOutcome::Incorrect { hint: Some(Hint::TooLow) }),
+            submission(1, "abc", SubmissionOutcome::Incorrect { hint: None }),
+            submission(2, "5", SubmissionOutcome::RateLimited { wait: None }),
+        ];
+        let day_one = PuzzleId::new(year!(2023), day!(1));
+
+        assert_eq!(check(&history, day_one, 1, "abc"), Err(Rejection::KnownWrong));
+        assert_eq!(check(&history, day_one, 1, "150"), Err(Rejection::AboveTooHigh { bound: "100".into() }));
+        assert_eq!(check(&history, day_one, 1, "20"), Err(Rejection::KnownWrong));
+        assert_eq!(check(&history, day_one, 1, "3"), Err(Rejection::BelowTooLow { bound: "20".into() }));
+        assert_eq!(check(&history, day_one, 1, "50"), Ok(()));
+        assert_eq!(check(&history, day_one, 1, "xyz"), Ok(()));
+        // rate limited submissions were never checked.
+        assert_eq!(check(&history, day_one, 2, "5"), Ok(()));
+        assert_eq!(check(&history, PuzzleId::new(year!(2023), day!(2)), 1, "150"), Ok(()));
+        assert_eq!(check(&history, PuzzleId::new(year!(2022), day!(1)), 1, "150"), Ok(()));
+    }
+}
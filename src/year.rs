@@ -0,0 +1,213 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::Day;
+
+/// A valid Advent of Code event year (i.e. an integer from 2015 onwards).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The year of the first event.
+    pub const FIRST: u16 = 2015;
+
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < Self::FIRST {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Parses the `AOC_YEAR` value a solution was compiled with. Fails to compile if it is missing or invalid.
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __from_env(value: Option<&str>) -> Self {
+        let Some(value) = value else {
+            panic!("AOC_YEAR is not set, pass the year to `solution!(year, day)` or set it in `.cargo/config.toml`");
+        };
+
+        let bytes = value.as_bytes();
+        let mut year: u16 = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(bytes[i].is_ascii_digit() && i < 4, "AOC_YEAR is not a valid year");
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        assert!(year >= Self::FIRST, "AOC_YEAR is not a valid year");
+        Self(year)
+    }
+
+    /// The default year, configured with the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The last day of the event. Events since 2025 have 12 days, earlier ones 25.
+    pub const fn last_day(self) -> Day {
+        if self.0 >= 2025 {
+            Day::__new_unchecked(12)
+        } else {
+            Day::__new_unchecked(25)
+        }
+    }
+
+    /// Whether `day` is part of the event.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.last_day()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year from {} onwards", Year::FIRST)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Every year that has solutions in `src/bin/<year>`, in ascending order.
+pub fn scaffolded_years() -> Vec<Year> {
+    let Ok(entries) = fs::read_dir("src/bin") else {
+        return vec![];
+    };
+
+    let mut years: Vec<Year> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .collect();
+
+    years.sort_unstable();
+    years
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a puzzle by the year of its event and its day.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the puzzle's solution bin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] without checking that `day` is part of the event, see [`PuzzleId::checked`].
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates a [`PuzzleId`] if `day` is part of the event of `year`, returns [`None`] otherwise.
+    pub fn checked(year: Year, day: Day) -> Option<Self> {
+        year.has_day(day).then_some(Self { year, day })
+    }
+
+    /// Path of the solution bin's source file.
+    pub fn bin_path(&self) -> PathBuf {
+        PathBuf::from(format!("src/bin/{}/{}.rs", self.year, self.day))
+    }
+
+    /// Path of the puzzle's file in a folder of `data/<year>`, e.g. `inputs` or `examples`.
+    pub fn data_path(&self, folder: &str, extension: &str) -> PathBuf {
+        PathBuf::from(format!("data/{}/{folder}/{}.{extension}", self.year, self.day))
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= $crate::Year::FIRST,
+            concat!("invalid year `", $year, "`, expecting a value from 2015 onwards"),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::{day, Day};
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2023".parse::<Year>().unwrap(), Year(2023));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("abc".parse::<Year>().is_err());
+        assert_eq!(Year::__from_env(Some("2019")), Year(2019));
+        assert_eq!(Year::new(2023).unwrap().to_string(), "2023");
+    }
+
+    #[test]
+    fn last_days() {
+        assert_eq!(Year(2015).last_day(), Day::new(25).unwrap());
+        assert_eq!(Year(2024).last_day(), Day::new(25).unwrap());
+        assert_eq!(Year(2025).last_day(), Day::new(12).unwrap());
+        assert!(PuzzleId::checked(Year(2025), day!(12)).is_some());
+        assert!(PuzzleId::checked(Year(2025), day!(13)).is_none());
+    }
+
+    #[test]
+    fn puzzle_paths() {
+        let id = PuzzleId::new(year!(2022), day!(9));
+        assert_eq!(id.to_string(), "2022-09");
+        assert_eq!(id.bin_path().to_str(), Some("src/bin/2022/09.rs"));
+        assert_eq!(id.data_path("examples", "txt").to_str(), Some("data/2022/examples/09.txt"));
+
+        let id = PuzzleId::new(year!(2023), day!(5));
+        assert_eq!(id.to_string(), "2023-05");
+        assert_eq!(id.data_path("inputs", "txt").to_str(), Some("data/2023/inputs/05.txt"));
+    }
+}